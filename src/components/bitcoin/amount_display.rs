@@ -2,7 +2,8 @@ use rust_on_rails::prelude::*;
 use crate::elements::images::Icon;
 use crate::elements::text::{Text, TextStyle};
 use crate::layout::{Column, Offset, Padding, Row, Size, Stack};
-use crate::PelicanUI;
+use crate::events::InputValidity;
+use crate::{PelicanUI, ElementID};
 
 #[derive(Debug, Component)]
 pub struct AmountDisplay(Column, Text, SubText);
@@ -52,16 +53,34 @@ impl SubText {
 
 
 #[derive(Debug, Component)]
-pub struct AmountInput(Stack, AmountInputContent);
-impl OnEvent for AmountInput {}
+pub struct AmountInput(Stack, AmountInputContent, #[skip] ElementID, #[skip] Option<bool>);
+
+impl OnEvent for AmountInput {
+    fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if let Some(TickEvent) = event.downcast_ref() {
+            let valid = !*self.error();
+            if self.3 != Some(valid) {
+                self.3 = Some(valid);
+                ctx.trigger_event(InputValidity(self.2, valid));
+            }
+        }
+        true
+    }
+}
+
 impl AmountInput {
     pub fn new(ctx: &mut Context) -> Self {
         AmountInput (
             Stack(Offset::Center, Offset::Center, Size::Fit, Size::fill(), Padding::default()),
             AmountInputContent::new(ctx),
+            ElementID::new(),
+            None
         )
     }
 
+    /// Identifies this input in `InputValidity` events.
+    pub fn id(&self) -> ElementID {self.2}
+
     pub fn usd(&mut self) -> String {self.1.1.value()}
     pub fn btc(&mut self) -> &mut f32 { &mut self.1.5 }
     pub fn error(&mut self) -> &mut bool {self.1.2.error()}
@@ -1,40 +1,33 @@
 use rust_on_rails::prelude::*;
 use crate::components::button::Button;
-use crate::layout::{Column, Row, Offset, Size, Padding};
+use crate::layout::{Grid, Stack, Offset, Size, Padding};
+
+const MAX_WIDTH: f32 = 375.0; // the keys share at most the width of a phone screen
 
 #[derive(Debug, Component)]
-pub struct NumericKeypad(Column, ButtonRow, ButtonRow, ButtonRow, ButtonRow);
+pub struct NumericKeypad(Stack, KeypadKeys);
 impl OnEvent for NumericKeypad {}
 
 impl NumericKeypad {
     pub fn new(ctx: &mut Context) -> Self {
+        let mut keys: Vec<Button> = ["1", "2", "3", "4", "5", "6", "7", "8", "9", ".", "0"].into_iter()
+            .map(|txt| Button::keypad(ctx, Some(txt), None, move |ctx: &mut Context| fire(ctx, Key::Character(SmolStr::new_static(txt)))))
+            .collect();
+        keys.push(Button::keypad(ctx, None, Some("back"), |ctx: &mut Context| fire(ctx, Key::Named(NamedKey::Backspace))));
+
+        // Fraction columns request an unbounded width, the stack keeps the keypad phone sized
+        let width = Size::custom(|widths: Vec<(f32, f32)>| (widths[0].0, widths[0].0.max(MAX_WIDTH)));
         NumericKeypad(
-            Column::new(16.0, Offset::Center, Size::Fit, Padding::default()), 
-            ButtonRow::new(ctx, Some("1"), Some("2"), Some("3")),
-            ButtonRow::new(ctx, Some("4"), Some("5"), Some("6")),
-            ButtonRow::new(ctx, Some("7"), Some("8"), Some("9")),
-            ButtonRow::new(ctx, Some("."), Some("0"), None),
+            Stack(Offset::Center, Offset::Center, width, Size::Fit, Padding::default()),
+            KeypadKeys(Grid::uniform(3, (16.0, 16.0)), keys)
         )
     }
 }
 
 #[derive(Debug, Component)]
-struct ButtonRow(Row, Button, Button, Button);
-impl OnEvent for ButtonRow {}
-
-impl ButtonRow {
-    fn new(ctx: &mut Context, a: Option<&'static str>, b: Option<&'static str>, c: Option<&'static str>) -> Self {
-        let key = |ctx: &mut Context, a: Option<&'static str>| {
-            match a {
-                Some(txt) => Button::keypad(ctx, Some(txt), None, move |ctx: &mut Context| fire(ctx, Key::Character(SmolStr::new_static(txt)))),
-                None => Button::keypad(ctx, None, Some("back"), |ctx: &mut Context| fire(ctx, Key::Named(NamedKey::Backspace)))
-            }
-        };
-        
-        ButtonRow(Row::center(16.0), key(ctx, a), key(ctx, b), key(ctx, c))        
-    }
-}
+struct KeypadKeys(Grid, Vec<Button>);
+impl OnEvent for KeypadKeys {}
 
 fn fire(ctx: &mut Context, key: Key) {
     ctx.trigger_event(KeyboardEvent{state: KeyboardState::Pressed, key})
-}
\ No newline at end of file
+}
@@ -4,6 +4,9 @@ use crate::elements::text::{Text, TextStyle};
 use crate::elements::shapes::RoundedRectangle;
 use crate::layout::{Column, Stack, Offset, Size, Padding};
 use crate::PelicanUI;
+use std::time::Instant;
+
+const PULSE: f32 = 1.2; // seconds per breath of the guide while the camera starts
 
 #[derive(Debug, Component)]
 pub struct QRCodeScanner(Stack, Option<Image>, QRGuide, #[skip] Camera);
@@ -42,8 +45,23 @@ impl OnEvent for QRCodeScanner {
 
 
 #[derive(Debug, Component)]
-struct QRGuide(Stack, Option<RoundedRectangle>, RoundedRectangle, Option<Message>);
-impl OnEvent for QRGuide {}
+struct QRGuide(Stack, Option<RoundedRectangle>, RoundedRectangle, Option<Message>, #[skip] Instant);
+
+impl OnEvent for QRGuide {
+    fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if let Some(TickEvent) = event.downcast_ref() {
+            let ui = ctx.get::<PelicanUI>();
+            let mut color = ui.theme.colors.outline.secondary;
+            // Breathes while a message covers the camera, holds steady once frames arrive or motion is reduced
+            if ui.animate() && self.3.is_some() {
+                let phase = self.4.elapsed().as_secs_f32() / PULSE * std::f32::consts::TAU;
+                color.3 = (color.3 as f32 * (0.65 + 0.35 * phase.cos())) as u8;
+            }
+            self.2.shape().color = color;
+        }
+        true
+    }
+}
 
 impl QRGuide {
     pub fn new(ctx: &mut Context) -> Self {
@@ -53,7 +71,8 @@ impl QRGuide {
             Stack(Offset::Center, Offset::Center, Size::Static(308.0), Size::Static(308.0), Padding::default()), 
             Some(RoundedRectangle::new(0.0, 8.0, background)), 
             RoundedRectangle::new(4.0, 8.0, color), 
-            Some(Message::new(ctx, "camera", "Accessing device camera."))
+            Some(Message::new(ctx, "camera", "Accessing device camera.")),
+            Instant::now()
         )
     }
 
@@ -11,4 +11,12 @@ pub use card::*;
 pub mod data_item;
 pub use data_item::*;
 pub mod list_item;
-pub use list_item::*;
\ No newline at end of file
+pub use list_item::*;
+pub mod scroll_view;
+pub use scroll_view::*;
+pub mod virtual_list;
+pub use virtual_list::*;
+pub mod dialog;
+pub use dialog::*;
+pub mod bottom_sheet;
+pub use bottom_sheet::*;
\ No newline at end of file
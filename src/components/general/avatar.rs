@@ -2,6 +2,8 @@ use rust_on_rails::prelude::*;
 use crate::elements::images::Icon;
 use crate::elements::shapes::{Outline, Circle};
 use crate::layout::{Stack, Offset, Size, Row, Padding};
+use crate::events::ThemeChanged;
+use crate::theme::colors::ColorResources;
 use crate::PelicanUI;
 
 #[derive(Debug, Clone)]
@@ -22,7 +24,11 @@ pub enum AvatarIconStyle {
 
 impl AvatarIconStyle {
     fn get(&self, ctx: &mut Context) -> (Color, Color) {
-        let colors = &ctx.get::<PelicanUI>().theme.colors;
+        self.colors(&ctx.get::<PelicanUI>().theme.colors)
+    }
+
+    /// Background and icon color.
+    pub fn colors(&self, colors: &ColorResources) -> (Color, Color) {
         match self {
             AvatarIconStyle::Primary => (colors.text.heading, colors.background.primary),
             AvatarIconStyle::Secondary => (colors.background.secondary, colors.text.secondary),
@@ -58,8 +64,18 @@ impl Avatar {
 }
 
 #[derive(Debug, Component)]
-struct AvatarIcon(Stack, Shape, Image);
-impl OnEvent for AvatarIcon {}
+struct AvatarIcon(Stack, Shape, Image, #[skip] AvatarIconStyle);
+
+impl OnEvent for AvatarIcon {
+    fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if event.downcast_ref::<ThemeChanged>().is_some() {
+            let (background, icon_color) = self.3.get(ctx);
+            self.1.color = background;
+            self.2.color = Some(icon_color);
+        }
+        true
+    }
+}
 
 impl AvatarIcon {
     fn new(ctx: &mut Context, name: &'static str, style: AvatarIconStyle, size: f32) -> Self {
@@ -68,7 +84,8 @@ impl AvatarIcon {
         AvatarIcon(
             Stack::center(),
             Circle::new(size - 2.0, background), 
-            Icon::new(ctx, name, icon_color, icon_size)
+            Icon::new(ctx, name, icon_color, icon_size),
+            style
         )
     }
 }
@@ -0,0 +1,255 @@
+use rust_on_rails::prelude::*;
+use crate::elements::shapes::{Rectangle, RoundedRectangle};
+use crate::components::scroll_view::ScrollView;
+use crate::events::{OverlayDismissed, ThemeChanged};
+use crate::interface::{OverlayItem, OverlayAnchor};
+use crate::layout::{Column, Stack, Bin, Offset, Size, Padding};
+use crate::{PelicanUI, ElementID};
+
+const HANDLE: f32 = 32.0; // height of the grabbable strip at the top of the sheet
+const RADIUS: f32 = 16.0;
+const EASE: f32 = 0.25; // share of the remaining distance covered per tick
+const MODAL_WIDTH: f32 = 480.0; // width of the desktop fallback
+
+/// Height a bottom sheet can rest at.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Detent {
+    Fraction(f32),
+    Static(f32),
+}
+
+impl Detent {
+    pub fn medium() -> Self {Detent::Fraction(0.5)}
+    pub fn large() -> Self {Detent::Fraction(0.9)}
+
+    pub fn get(&self, screen: f32) -> f32 {
+        match self {
+            Detent::Fraction(f) => screen * f,
+            Detent::Static(h) => h.min(screen),
+        }
+    }
+}
+
+/// Slides up from the bottom of compact screens and snaps to its detents, shown as a centered modal otherwise.
+#[derive(Debug)]
+pub struct BottomSheet {
+    scrim: Rectangle,
+    sheet: Sheet,
+    detents: Vec<Detent>,
+    detent: usize,
+    height: f32,
+    screen: (f32, f32),
+    area: ((f32, f32), (f32, f32)),
+    drag: Option<(f32, f32)>,
+    dismissal: Dismissal,
+    id: ElementID,
+}
+
+impl BottomSheet {
+    pub fn new(ctx: &mut Context, mut detents: Vec<Detent>, content: Vec<Box<dyn Drawable>>) -> Self {
+        assert!(!detents.is_empty(), "A bottom sheet needs at least one detent");
+        let scrim = ctx.get::<PelicanUI>().theme.colors.shades.darken;
+        detents.sort_by(|a, b| a.get(1.0).total_cmp(&b.get(1.0)));
+        BottomSheet{
+            scrim: Rectangle::new(scrim), sheet: Sheet::new(ctx, content), detents, detent: 0,
+            height: 0.0, screen: (0.0, 0.0), area: ((0.0, 0.0), (0.0, 0.0)), drag: None, dismissal: Dismissal::Open, id: ElementID::new()
+        }
+    }
+
+    /// Shows `sheet` above the interface and returns its id.
+    pub fn show(ctx: &mut Context, sheet: BottomSheet) -> ElementID {
+        let id = sheet.id;
+        let mut item = OverlayItem::new(sheet, OverlayAnchor::Screen(Offset::Start, Offset::Start));
+        item.id = id;
+        item.z = 50;
+        item.dismissible = false;
+        item.modal = true;
+        ctx.get::<PelicanUI>().overlays.show(item)
+    }
+
+    pub fn id(&self) -> ElementID {self.id}
+    pub fn detent(&self) -> usize {self.detent}
+    pub fn content(&mut self) -> &mut ScrollView {&mut self.sheet.2.2.1}
+
+    /// Slides the sheet down before removing it.
+    pub fn dismiss(&mut self) {self.dismissal.start();}
+
+    fn compact(ctx: &mut Context) -> bool {ctx.get::<PelicanUI>().size_class().is_compact()}
+
+    fn target(&self) -> f32 {
+        match self.dismissal {
+            Dismissal::Open => self.detents[self.detent].get(self.screen.1),
+            _ => 0.0,
+        }
+    }
+
+    // Snaps to the detent closest to the release height, dismisses below half the smallest one
+    fn settle(&mut self) {
+        let heights: Vec<f32> = self.detents.iter().map(|d| d.get(self.screen.1)).collect();
+        if self.height < heights[0] / 2.0 {return self.dismiss();}
+        self.detent = heights.iter().enumerate()
+            .min_by(|a, b| (a.1 - self.height).abs().total_cmp(&(b.1 - self.height).abs()))
+            .map(|(i, _)| i).unwrap_or_default();
+    }
+}
+
+impl OnEvent for BottomSheet {
+    fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if let Some(TickEvent) = event.downcast_ref() {
+            if self.drag.is_none() {
+                let target = self.target();
+                self.height = match ctx.get::<PelicanUI>().animate() && BottomSheet::compact(ctx) {
+                    true if (target - self.height).abs() > 0.5 => self.height + (target - self.height) * EASE,
+                    _ => target
+                };
+                if self.dismissal.finish(self.height) {
+                    ctx.get::<PelicanUI>().overlays.dismiss(self.id);
+                    ctx.trigger_event(OverlayDismissed(self.id));
+                }
+            }
+        } else if let Some(KeyboardEvent{state: KeyboardState::Pressed, key: Key::Named(NamedKey::Escape)}) = event.downcast_ref() {
+            self.dismiss();
+        } else if event.downcast_ref::<ThemeChanged>().is_some() {
+            self.scrim.shape().color = ctx.get::<PelicanUI>().theme.colors.shades.darken;
+        } else if let Some(event) = event.downcast_ref::<MouseEvent>() {
+            let compact = BottomSheet::compact(ctx);
+            let (offset, size) = self.area;
+            let inside = |p: &(f32, f32)| p.0 >= offset.0 && p.0 <= offset.0 + size.0 && p.1 >= offset.1 && p.1 <= offset.1 + size.1;
+            match event {
+                MouseEvent{state: MouseState::Pressed, position: Some(p)} if !inside(p) => self.dismiss(),
+                MouseEvent{state: MouseState::Pressed, position: Some(p)} if compact && p.1 < offset.1 + HANDLE => {
+                    self.drag = Some((p.1, self.height));
+                },
+                MouseEvent{state: MouseState::Moved, position: Some(p)} => if let Some((start, height)) = self.drag {
+                    let max = self.detents.last().map(|d| d.get(self.screen.1)).unwrap_or_default();
+                    self.height = (height + start - p.1).clamp(0.0, max);
+                },
+                MouseEvent{state: MouseState::Released, ..} if self.drag.is_some() => {
+                    self.drag = None;
+                    self.settle();
+                },
+                _ => {}
+            }
+        }
+        true
+    }
+}
+
+impl Component for BottomSheet {
+    fn children_mut(&mut self) -> Vec<&mut dyn Drawable> {vec![&mut self.scrim, &mut self.sheet]}
+    fn children(&self) -> Vec<&dyn Drawable> {vec![&self.scrim, &self.sheet]}
+
+    fn request_size(&self, _ctx: &mut Context, _children: Vec<SizeRequest>) -> SizeRequest {
+        SizeRequest::fill()
+    }
+
+    fn build(&mut self, ctx: &mut Context, size: (f32, f32), children: Vec<SizeRequest>) -> Vec<Area> {
+        self.screen = size;
+        let sheet = match BottomSheet::compact(ctx) {
+            // The rounded bottom corners sit below the screen edge
+            true => Area{offset: (0.0, size.1 - self.height), size: children[1].get((size.0, self.height + RADIUS))},
+            false => {
+                let sheet = children[1].get((MODAL_WIDTH.min(size.0 - 32.0), self.height.min(size.1 * 0.8)));
+                Area{offset: ((size.0 - sheet.0) / 2.0, (size.1 - sheet.1) / 2.0), size: sheet}
+            }
+        };
+        self.area = (sheet.offset, sheet.size);
+        vec![Area{offset: (0.0, 0.0), size}, sheet]
+    }
+}
+
+// The overlay is removed once, on the tick the sliding sheet reaches the bottom
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Dismissal {
+    Open,
+    Sliding,
+    Dismissed,
+}
+
+impl Dismissal {
+    fn start(&mut self) {
+        if *self == Dismissal::Open {*self = Dismissal::Sliding;}
+    }
+
+    fn finish(&mut self, height: f32) -> bool {
+        let finished = *self == Dismissal::Sliding && height == 0.0;
+        if finished {*self = Dismissal::Dismissed;}
+        finished
+    }
+}
+
+#[derive(Debug, Component)]
+struct Sheet(Stack, RoundedRectangle, SheetContent);
+
+impl OnEvent for Sheet {
+    fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if event.downcast_ref::<ThemeChanged>().is_some() {
+            self.1.shape().color = ctx.get::<PelicanUI>().theme.colors.background.primary;
+        }
+        true
+    }
+}
+
+impl Sheet {
+    fn new(ctx: &mut Context, content: Vec<Box<dyn Drawable>>) -> Self {
+        let color = ctx.get::<PelicanUI>().theme.colors.background.primary;
+        Sheet(
+            Stack(Offset::Center, Offset::Start, Size::fill(), Size::fill(), Padding::default()),
+            RoundedRectangle::new(0.0, RADIUS, color),
+            SheetContent::new(ctx, content)
+        )
+    }
+}
+
+#[derive(Debug, Component)]
+struct SheetContent(Column, Bin<Stack, RoundedRectangle>, Bin<Stack, ScrollView>);
+
+impl OnEvent for SheetContent {
+    fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if event.downcast_ref::<ThemeChanged>().is_some() {
+            self.1.inner().shape().color = ctx.get::<PelicanUI>().theme.colors.outline.secondary;
+        }
+        true
+    }
+}
+
+impl SheetContent {
+    fn new(ctx: &mut Context, content: Vec<Box<dyn Drawable>>) -> Self {
+        let color = ctx.get::<PelicanUI>().theme.colors.outline.secondary;
+        let height = Size::custom(move |_: Vec<(f32, f32)>|(0.0, f32::MAX));
+        SheetContent(
+            Column::new(12.0, Offset::Center, Size::fill(), Padding(0.0, 12.0, 0.0, RADIUS)),
+            Bin (
+                Stack(Offset::Center, Offset::Center, Size::Static(36.0), Size::Static(5.0), Padding::default()),
+                RoundedRectangle::new(0.0, 2.5, color)
+            ),
+            Bin (
+                Stack(Offset::Center, Offset::Start, Size::fill(), height, Padding(24.0, 0.0, 24.0, 0.0)),
+                ScrollView::vertical(24.0, content)
+            )
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dismissal_finishes_once() {
+        let mut dismissal = Dismissal::Open;
+        assert!(!dismissal.finish(0.0));
+        dismissal.start();
+        let sent = [120.0, 30.0, 0.0, 0.0, 0.0].into_iter().filter(|height| dismissal.finish(*height)).count();
+        assert_eq!(sent, 1);
+        dismissal.start();
+        assert_eq!(dismissal, Dismissal::Dismissed);
+        assert!(!dismissal.finish(0.0));
+    }
+
+    #[test]
+    fn detents_clamp_to_the_screen() {
+        assert_eq!(Detent::medium().get(800.0), 400.0);
+        assert_eq!(Detent::Static(300.0).get(200.0), 200.0);
+    }
+}
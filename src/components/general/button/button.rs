@@ -4,7 +4,8 @@ use crate::components::avatar::{Avatar, AvatarContent};
 use crate::elements::images::Icon;
 use crate::elements::shapes::OutlinedRectangle;
 use crate::elements::text::{Text, TextStyle};
-use crate::events::RemoveContactEvent;
+use crate::components::dialog::Dialog;
+use crate::events::{RemoveContactEvent, ThemeChanged};
 use crate::layout::{Offset, Padding, Row, Size, Stack, Wrap};
 
 use super::{ButtonSize, ButtonState, ButtonStyle};
@@ -46,7 +47,7 @@ impl Button {
             ),
         };
 
-        let background = OutlinedRectangle::new(colors.background, colors.outline, height/2.0, 1.0);
+        let background = OutlinedRectangle::new(ctx, colors.background, colors.outline, height/2.0, 1.0);
         let layout = Stack(offset, Offset::Center, width, Size::Static(height), Padding::default());
 
         Button(layout, background, content, style, state, Box::new(on_click))
@@ -62,9 +63,18 @@ impl Button {
     pub fn status(&mut self) -> &mut ButtonState {&mut self.4}
 }
 
+impl Button {
+    // Theme and mouse events are handled here, others like `AccessibilityChanged` reach the outline
+    fn consumes(event: &dyn Event) -> bool {
+        event.downcast_ref::<ThemeChanged>().is_some() || event.downcast_ref::<MouseEvent>().is_some()
+    }
+}
+
 impl OnEvent for Button {
     fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
-        if let Some(event) = event.downcast_ref::<MouseEvent>() {
+        if event.downcast_ref::<ThemeChanged>().is_some() {
+            self.color(ctx);
+        } else if let Some(event) = event.downcast_ref::<MouseEvent>() {
             if let Some(_) = self.4.handle(ctx, *event) {
                 self.color(ctx);
             }
@@ -75,7 +85,7 @@ impl OnEvent for Button {
                 }
             }
         }
-        false
+        !Button::consumes(event)
     }
 }
 
@@ -320,9 +330,36 @@ impl OnEvent for QuickDeselectButton {}
 impl QuickDeselectButton {
     pub fn new(ctx: &mut Context, name: &'static str, id: ElementID) -> Self {
         // Wrap of secondary contact buttons
-        let button = Button::secondary(ctx, None, name, Some("close"), move |ctx: &mut Context| ctx.trigger_event(RemoveContactEvent(id)));
+        let button = Button::secondary(ctx, None, name, Some("close"), move |ctx: &mut Context| {
+            Dialog::confirm(ctx, "Remove contact?", "You can add them again later.", "Remove", move |ctx: &mut Context| {
+                ctx.trigger_event(RemoveContactEvent(id))
+            });
+        });
         QuickDeselectButton(Stack::default(), button, id)
     }
 
     pub fn id(&self) -> ElementID {self.2}
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Accessibility;
+    use crate::events::AccessibilityChanged;
+
+    #[test]
+    fn accessibility_changes_rescale_the_outline() {
+        let high_contrast = Accessibility{high_contrast: true, ..Default::default()};
+        assert!(!Button::consumes(&AccessibilityChanged(high_contrast)));
+        assert!(Button::consumes(&ThemeChanged));
+        assert!(Button::consumes(&MouseEvent{state: MouseState::Pressed, position: None}));
+
+        let color = Color::from_hex("000000", 255);
+        let mut outline = OutlinedRectangle::scaled(color, color, 24.0, 1.0, Accessibility::default().stroke_scale());
+        assert_eq!(outline.stroke(), 1.0);
+        outline.rescale(high_contrast);
+        assert_eq!(outline.stroke(), 2.0);
+        outline.rescale(Accessibility::default());
+        assert_eq!(outline.stroke(), 1.0);
+    }
+}
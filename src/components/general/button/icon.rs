@@ -1,6 +1,7 @@
 use rust_on_rails::prelude::*;
 use crate::elements::images::Icon;
 use crate::elements::shapes::OutlinedRectangle;
+use crate::events::ThemeChanged;
 use crate::layout::{Offset, Padding, Size, Row, Stack};
 
 use super::{ButtonSize, ButtonState, ButtonStyle};
@@ -34,7 +35,7 @@ impl IconButton {
         };
 
         let icon = Icon::new(ctx, icon, colors.label, icon_size);
-        let background = OutlinedRectangle::new(colors.background, colors.outline, radius, 1.0);
+        let background = OutlinedRectangle::new(ctx, colors.background, colors.outline, radius, 1.0);
 
 
         IconButton(
@@ -55,7 +56,10 @@ impl IconButton {
 
 impl OnEvent for IconButton {
     fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
-        if let Some(event) = event.downcast_ref::<MouseEvent>() {
+        if event.downcast_ref::<ThemeChanged>().is_some() {
+            self.color(ctx, self.4);
+            false
+        } else if let Some(event) = event.downcast_ref::<MouseEvent>() {
             if let Some(state) = self.4.handle(ctx, *event) {
                 self.color(ctx, state);
             }
@@ -19,7 +19,7 @@ impl Card {
     ) -> Self {
         let colors = ctx.get::<PelicanUI>().theme.colors;
         let (bg, oc) = (colors.background.primary, colors.outline.secondary);
-        let background = OutlinedRectangle::new(bg, oc, 16.0, 1.0);
+        let background = OutlinedRectangle::new(ctx, bg, oc, 16.0, 1.0);
         let content = CardContent::new(ctx, avatar, title, subtitle, description);
         let layout = Stack(
             Offset::Center, Offset::Center, 
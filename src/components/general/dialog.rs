@@ -0,0 +1,189 @@
+use rust_on_rails::prelude::*;
+use crate::elements::images::Brand;
+use crate::elements::shapes::{OutlinedRectangle, Rectangle};
+use crate::elements::text::{Text, TextStyle};
+use crate::components::button::{Button, ButtonSize, ButtonStyle, ButtonState, ButtonWidth};
+use crate::events::{DialogChoice, ThemeChanged};
+use crate::interface::{OverlayItem, OverlayAnchor};
+use crate::layout::{Column, Stack, Offset, Size, Padding};
+use crate::{PelicanUI, ElementID};
+
+const WIDTH: f32 = 320.0;
+
+/// A centered card over a scrim, reports the index of the pressed button or `None` when dismissed.
+#[derive(Component)]
+pub struct Dialog(
+    Stack, Rectangle, DialogCard,
+    #[skip] ElementID,
+    #[skip] Box<dyn FnMut(&mut Context, Option<usize>)>,
+    #[skip] usize,
+    #[skip] bool,
+    #[skip] Option<usize>,
+);
+
+impl Dialog {
+    /// The last of the one to three `buttons` is the primary action.
+    /// Enter only chooses a button marked with `default_button`.
+    pub fn new(
+        ctx: &mut Context,
+        title: &'static str,
+        body: &'static str,
+        illustration: Option<&'static str>,
+        buttons: Vec<&'static str>,
+        dismissible: bool,
+        on_choice: impl FnMut(&mut Context, Option<usize>) + 'static,
+    ) -> Self {
+        assert!((1..=3).contains(&buttons.len()), "A dialog has one to three buttons");
+        let id = ElementID::new();
+        let scrim = ctx.get::<PelicanUI>().theme.colors.shades.darken;
+        let count = buttons.len();
+        Dialog(
+            Stack(Offset::Center, Offset::Center, Size::fill(), Size::fill(), Padding::default()),
+            Rectangle::new(scrim),
+            DialogCard::new(ctx, id, title, body, illustration, buttons, dismissible),
+            id, Box::new(on_choice), count, false, None
+        )
+    }
+
+    /// Button chosen by Enter on desktop, never mark a destructive action as the default.
+    pub fn default_button(mut self, index: usize) -> Self {
+        self.7 = (index < self.5).then_some(index);
+        self
+    }
+
+    /// Shows `dialog` above the interface and returns its id.
+    pub fn show(ctx: &mut Context, dialog: Dialog) -> ElementID {
+        let id = dialog.id();
+        let mut item = OverlayItem::new(dialog, OverlayAnchor::Screen(Offset::Center, Offset::Center));
+        item.id = id;
+        item.z = 100;
+        item.dismissible = false;
+        item.modal = true;
+        ctx.get::<PelicanUI>().overlays.show(item)
+    }
+
+    /// Asks for confirmation, `on_confirm` only runs when the confirm button is pressed.
+    /// Enter cancels, since the confirmed action is often destructive.
+    pub fn confirm(
+        ctx: &mut Context,
+        title: &'static str,
+        body: &'static str,
+        confirm: &'static str,
+        mut on_confirm: impl FnMut(&mut Context) + 'static,
+    ) -> ElementID {
+        let dialog = Dialog::new(ctx, title, body, None, vec!["Cancel", confirm], true, move |ctx: &mut Context, choice: Option<usize>| {
+            if choice == Some(1) {on_confirm(ctx);}
+        }).default_button(0);
+        Dialog::show(ctx, dialog)
+    }
+
+    pub fn id(&self) -> ElementID {self.3}
+}
+
+impl OnEvent for Dialog {
+    fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if let Some(DialogChoice(id, choice)) = event.downcast_ref::<DialogChoice>() {
+            if *id == self.3 && !self.6 {
+                self.6 = true;
+                ctx.get::<PelicanUI>().overlays.dismiss(self.3);
+                (self.4)(ctx, *choice);
+            }
+        } else if let Some(KeyboardEvent{state: KeyboardState::Pressed, key}) = event.downcast_ref() {
+            if !crate::config::IS_MOBILE {
+                match key {
+                    Key::Named(NamedKey::Enter) if self.7.is_some() => ctx.trigger_event(DialogChoice(self.3, self.7)),
+                    Key::Named(NamedKey::Escape) if self.2.4 => ctx.trigger_event(DialogChoice(self.3, None)),
+                    _ => {}
+                }
+            }
+        } else if event.downcast_ref::<ThemeChanged>().is_some() {
+            self.1.shape().color = ctx.get::<PelicanUI>().theme.colors.shades.darken;
+        }
+        true
+    }
+}
+
+impl std::fmt::Debug for Dialog {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Dialog(...)")
+    }
+}
+
+#[derive(Debug, Component)]
+struct DialogCard(Stack, OutlinedRectangle, DialogContent, #[skip] ElementID, #[skip] bool);
+
+impl DialogCard {
+    fn new(
+        ctx: &mut Context,
+        id: ElementID,
+        title: &'static str,
+        body: &'static str,
+        illustration: Option<&'static str>,
+        buttons: Vec<&'static str>,
+        dismissible: bool,
+    ) -> Self {
+        let colors = ctx.get::<PelicanUI>().theme.colors;
+        let background = OutlinedRectangle::new(ctx, colors.background.primary, colors.outline.secondary, 16.0, 1.0);
+        let layout = Stack(
+            Offset::Center, Offset::Center,
+            Size::Static(WIDTH),
+            Size::custom(|heights: Vec<(f32, f32)>| heights[1]),
+            Padding::default()
+        );
+        DialogCard(layout, background, DialogContent::new(ctx, id, title, body, illustration, buttons), id, dismissible)
+    }
+}
+
+impl OnEvent for DialogCard {
+    fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if let Some(MouseEvent{state: MouseState::Pressed, position: None}) = event.downcast_ref::<MouseEvent>() {
+            // Pressed on the scrim
+            if self.4 {ctx.trigger_event(DialogChoice(self.3, None));}
+        } else if event.downcast_ref::<ThemeChanged>().is_some() {
+            let colors = ctx.get::<PelicanUI>().theme.colors;
+            *self.1.background() = colors.background.primary;
+            *self.1.outline() = colors.outline.secondary;
+        }
+        true
+    }
+}
+
+#[derive(Debug, Component)]
+struct DialogContent(Column, Option<Image>, Text, Text, DialogButtons);
+impl OnEvent for DialogContent {}
+
+impl DialogContent {
+    fn new(
+        ctx: &mut Context,
+        id: ElementID,
+        title: &'static str,
+        body: &'static str,
+        illustration: Option<&'static str>,
+        buttons: Vec<&'static str>,
+    ) -> Self {
+        let theme = &ctx.get::<PelicanUI>().theme;
+        let (h4, md) = (theme.fonts.size.h4, theme.fonts.size.md);
+        let illustration = illustration.and_then(|name| theme.brand.illustrations.get(name)).map(|image| Brand::new(image, (96.0, 96.0)));
+        let primary = buttons.len() - 1;
+        let buttons = buttons.into_iter().enumerate().map(|(i, label)| {
+            let style = if i == primary {ButtonStyle::Primary} else {ButtonStyle::Secondary};
+            Button::new(
+                ctx, None, None, Some(label), None,
+                ButtonSize::Large, ButtonWidth::Expand, style, ButtonState::Default, Offset::Center,
+                move |ctx: &mut Context| ctx.trigger_event(DialogChoice(id, Some(i)))
+            )
+        }).collect();
+
+        DialogContent(
+            Column::new(16.0, Offset::Center, Size::Fit, Padding(24.0, 24.0, 24.0, 24.0)),
+            illustration,
+            Text::new(ctx, title, TextStyle::Heading, h4, Align::Center),
+            Text::new(ctx, body, TextStyle::Primary, md, Align::Center),
+            DialogButtons(Column::new(8.0, Offset::Center, Size::Fit, Padding(0.0, 8.0, 0.0, 0.0)), buttons)
+        )
+    }
+}
+
+#[derive(Debug, Component)]
+struct DialogButtons(Column, Vec<Button>);
+impl OnEvent for DialogButtons {}
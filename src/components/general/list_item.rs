@@ -1,5 +1,5 @@
 use rust_on_rails::prelude::*;
-use crate::events::{ListItemSelect, RemoveContactEvent, AddContactEvent};
+use crate::events::{ListItemSelect, RemoveContactEvent, AddContactEvent, ThemeChanged};
 use crate::elements::images::Icon;
 use crate::elements::text::{Text, TextStyle};
 use crate::elements::shapes::Rectangle;
@@ -45,6 +45,11 @@ impl ListItem {
 
 impl OnEvent for ListItem {
     fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if event.downcast_ref::<ThemeChanged>().is_some() {
+            self.1.shape().color = ctx.get::<PelicanUI>().theme.colors.background.primary;
+            self.2.color(ctx);
+            return true; // Texts and avatars recolor themselves
+        }
         if let Some(event) = event.downcast_ref::<MouseEvent>() {
             if let MouseEvent{state: MouseState::Pressed, position: Some(_)} = event {
                 self.2.1.as_mut().map(|radio| {radio.select(ctx); ctx.trigger_event(ListItemSelect(self.5.expect("Selectable List Items Require ElementIDs")));});
@@ -100,6 +105,12 @@ impl ListItemContent {
             caret.then(|| Icon::new(ctx, "forward", color, 16.0)),
         )
     }
+
+    fn color(&mut self, ctx: &mut Context) {
+        let colors = ctx.get::<PelicanUI>().theme.colors;
+        if let Some(radio) = &mut self.1 {radio.1.color = Some(colors.text.heading);}
+        if let Some(caret) = &mut self.4 {caret.color = Some(colors.text.secondary);}
+    }
 }
 
 #[derive(Debug, Component)]
@@ -369,7 +380,7 @@ impl OnEvent for QuickDeselectContent {}
 impl QuickDeselectContent {
     pub fn new(first: QuickDeselectButton) -> Self {
         QuickDeselectContent(
-            Wrap(8.0, 8.0, Offset::Start, Offset::Center, Padding::default()), 
+            Wrap(8.0, 8.0, Offset::Start, Offset::Center, Padding::default()),
             vec![first],
         )
     }
@@ -0,0 +1,253 @@
+use rust_on_rails::prelude::*;
+use crate::events::{ScrolledEvent, ScrollToEvent};
+use crate::layout::{Offset, UniformExpand};
+use crate::ElementID;
+
+use std::collections::HashMap;
+
+const FRICTION: f32 = 0.95; // velocity kept per tick while coasting
+const RESISTANCE: f32 = 0.35; // drag applied past the edges
+const SPRING: f32 = 0.2; // share of the overscroll recovered per tick
+const MIN_VELOCITY: f32 = 0.1;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ScrollAxis {
+    Vertical,
+    Horizontal,
+}
+
+impl ScrollAxis {
+    pub fn main(&self, size: (f32, f32)) -> f32 {
+        match self {
+            ScrollAxis::Vertical => size.1,
+            ScrollAxis::Horizontal => size.0,
+        }
+    }
+
+    pub fn cross(&self, size: (f32, f32)) -> f32 {
+        match self {
+            ScrollAxis::Vertical => size.0,
+            ScrollAxis::Horizontal => size.1,
+        }
+    }
+
+    pub fn join(&self, main: f32, cross: f32) -> (f32, f32) {
+        match self {
+            ScrollAxis::Vertical => (cross, main),
+            ScrollAxis::Horizontal => (main, cross),
+        }
+    }
+
+    // (min, max) along the main axis followed by the cross axis
+    pub fn request(&self, request: &SizeRequest) -> ((f32, f32), (f32, f32)) {
+        let widths = (request.min_width(), request.max_width());
+        let heights = (request.min_height(), request.max_height());
+        match self {
+            ScrollAxis::Vertical => (heights, widths),
+            ScrollAxis::Horizontal => (widths, heights),
+        }
+    }
+
+    pub fn delta(&self, x: f32, y: f32) -> f32 {
+        match self {
+            ScrollAxis::Vertical => y,
+            ScrollAxis::Horizontal if x == 0.0 => y,
+            ScrollAxis::Horizontal => x,
+        }
+    }
+}
+
+/// Scroll position with touch dragging, momentum and rubber band edges.
+/// Shared by every scrolling container so they all feel the same.
+#[derive(Clone, Debug, Default)]
+pub struct Scroller {
+    offset: f32,
+    max: f32,
+    velocity: f32,
+    touch: Option<f32>,
+    locked: bool,
+}
+
+impl Scroller {
+    pub fn offset(&self) -> f32 {self.offset}
+    pub fn max(&self) -> f32 {self.max}
+    pub fn is_dragging(&self) -> bool {self.touch.is_some()}
+    pub fn is_locked(&self) -> bool {self.locked}
+
+    /// A locked scroller stays at the start and ignores input, for content that scrolls itself.
+    pub fn lock(&mut self, locked: bool) {
+        self.locked = locked;
+        if locked {*self = Scroller{locked, ..Scroller::default()};}
+    }
+
+    pub fn set_max(&mut self, max: f32) {
+        self.max = if self.locked {0.0} else {max.max(0.0)};
+        if self.touch.is_none() && self.velocity == 0.0 && self.overscroll() == 0.0 {
+            self.offset = self.offset.clamp(0.0, self.max);
+        }
+    }
+
+    pub fn scroll_by(&mut self, delta: f32) {
+        self.velocity = 0.0;
+        self.offset = (self.offset + delta).clamp(0.0, self.max);
+    }
+
+    pub fn scroll_to(&mut self, offset: f32) {
+        self.velocity = 0.0;
+        self.offset = offset.clamp(0.0, self.max);
+    }
+
+    pub fn press(&mut self, position: f32) {
+        if self.locked {return;}
+        self.touch = Some(position);
+        self.velocity = 0.0;
+    }
+
+    pub fn drag(&mut self, position: f32) {
+        if let Some(last) = self.touch.replace(position) {
+            let delta = last - position;
+            let resistance = if self.overscroll() != 0.0 {RESISTANCE} else {1.0};
+            self.offset += delta * resistance;
+            self.velocity = delta;
+            if !crate::config::IS_MOBILE {self.offset = self.offset.clamp(0.0, self.max);}
+        }
+    }
+
+    pub fn release(&mut self) {
+        self.touch = None;
+    }
+
+    /// Applies wheel scrolling and touch drags along `axis`.
+    pub fn mouse(&mut self, axis: ScrollAxis, event: &MouseEvent) {
+        match event {
+            MouseEvent{state: MouseState::Scroll(x, y), position: Some(_)} => self.scroll_by(axis.delta(*x, *y)),
+            MouseEvent{state: MouseState::Pressed, position: Some(p)} if crate::config::IS_MOBILE => self.press(axis.main(*p)),
+            MouseEvent{state: MouseState::Moved, position: Some(p)} if self.is_dragging() => self.drag(axis.main(*p)),
+            MouseEvent{state: MouseState::Released, ..} => self.release(),
+            _ => {}
+        }
+    }
+
+    /// Advances momentum and spring back by one frame, returns true when the offset moved.
+    pub fn tick(&mut self) -> bool {
+        if self.touch.is_some() {return false;}
+        let start = self.offset;
+
+        if self.velocity.abs() > MIN_VELOCITY {
+            self.offset += self.velocity;
+            self.velocity *= if self.overscroll() != 0.0 {RESISTANCE} else {FRICTION};
+        } else {
+            self.velocity = 0.0;
+        }
+
+        let over = self.overscroll();
+        if over != 0.0 && self.velocity == 0.0 {
+            self.offset -= over * SPRING;
+            if (over * (1.0 - SPRING)).abs() < 0.5 {self.offset = self.offset.clamp(0.0, self.max);}
+        }
+
+        if !crate::config::IS_MOBILE {self.offset = self.offset.clamp(0.0, self.max);}
+        self.offset != start
+    }
+
+    fn overscroll(&self) -> f32 {
+        if self.offset < 0.0 {self.offset} else if self.offset > self.max {self.offset - self.max} else {0.0}
+    }
+}
+
+#[derive(Debug)]
+pub struct ScrollView(
+    ScrollAxis, f32, Offset, Vec<Box<dyn Drawable>>, Scroller,
+    ElementID, HashMap<ElementID, usize>, Vec<f32>
+);
+// axis, spacing, cross axis offset, children, scroller, id, anchors, child starts
+
+impl ScrollView {
+    pub fn new(axis: ScrollAxis, spacing: f32, offset: Offset, children: Vec<Box<dyn Drawable>>) -> Self {
+        ScrollView(axis, spacing, offset, children, Scroller::default(), ElementID::new(), HashMap::new(), vec![])
+    }
+
+    pub fn vertical(spacing: f32, children: Vec<Box<dyn Drawable>>) -> Self {
+        ScrollView::new(ScrollAxis::Vertical, spacing, Offset::Center, children)
+    }
+
+    pub fn horizontal(spacing: f32, children: Vec<Box<dyn Drawable>>) -> Self {
+        ScrollView::new(ScrollAxis::Horizontal, spacing, Offset::Center, children)
+    }
+
+    /// Registers the child at `index` as the target of `ScrollToEvent(id)`.
+    pub fn anchor(&mut self, id: ElementID, index: usize) {
+        self.6.insert(id, index);
+    }
+
+    pub fn scroll_to(&mut self, id: ElementID) -> bool {
+        match self.6.get(&id).and_then(|i| self.7.get(*i)) {
+            Some(start) => {self.4.scroll_to(*start); true},
+            None => false
+        }
+    }
+
+    pub fn id(&self) -> ElementID {self.5}
+    pub fn items(&mut self) -> &mut Vec<Box<dyn Drawable>> {&mut self.3}
+    pub fn scroller(&mut self) -> &mut Scroller {&mut self.4}
+}
+
+impl OnEvent for ScrollView {
+    fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
+        let start = self.4.offset();
+        if let Some(TickEvent) = event.downcast_ref() {
+            self.4.tick();
+        } else if let Some(ScrollToEvent(id)) = event.downcast_ref::<ScrollToEvent>() {
+            self.scroll_to(*id);
+        } else if let Some(event) = event.downcast_ref::<MouseEvent>() {
+            self.4.mouse(self.0, event);
+        }
+
+        if self.4.offset() != start {
+            ctx.trigger_event(ScrolledEvent(self.5, self.4.offset()));
+        }
+        true
+    }
+}
+
+impl Component for ScrollView {
+    fn children_mut(&mut self) -> Vec<&mut dyn Drawable> {self.3.iter_mut().map(|c| c.as_mut()).collect()}
+    fn children(&self) -> Vec<&dyn Drawable> {self.3.iter().map(|c| c.as_ref()).collect()}
+
+    fn request_size(&self, _ctx: &mut Context, children: Vec<SizeRequest>) -> SizeRequest {
+        let spacing = self.1 * children.len().saturating_sub(1) as f32;
+        let (content, cross) = children.iter().map(|i| self.0.request(i)).fold(
+            (spacing, (0.0f32, 0.0f32)),
+            |(content, cross), (m, c)| (content + m.0, (cross.0.max(c.0), cross.1.max(c.1)))
+        );
+        let (min, max) = (self.0.join(0.0, cross.0), self.0.join(content, cross.1));
+        SizeRequest::new(min.0, min.1, max.0, max.1)
+    }
+
+    fn build(&mut self, _ctx: &mut Context, size: (f32, f32), children: Vec<SizeRequest>) -> Vec<Area> {
+        let (viewport, cross) = (self.0.main(size), self.0.cross(size));
+        if children.is_empty() {
+            self.4.set_max(0.0);
+            self.7.clear();
+            return vec![];
+        }
+
+        let mains = UniformExpand::get(children.iter().map(|i| self.0.request(i).0).collect(), viewport, self.1);
+
+        let mut offset = 0.0;
+        self.7.clear();
+        let areas = children.into_iter().zip(mains).map(|(i, main)| {
+            let size = i.get(self.0.join(main, cross));
+            self.7.push(offset);
+            let area = Area{
+                offset: self.0.join(offset - self.4.offset(), self.2.get(cross, self.0.cross(size))),
+                size
+            };
+            offset += self.0.main(size) + self.1;
+            area
+        }).collect();
+
+        self.4.set_max(offset - self.1 - viewport);
+        areas
+    }
+}
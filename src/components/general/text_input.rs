@@ -2,15 +2,15 @@ use rust_on_rails::prelude::*;
 use crate::elements::shapes::OutlinedRectangle;
 use crate::elements::text::{ExpandableText, Text, TextStyle};
 use crate::components::button::IconButton;
-use crate::events::{KeyboardActiveEvent, SetActiveInput};
+use crate::events::{KeyboardActiveEvent, SetActiveInput, InputValidity, ThemeChanged};
 use crate::layout::{EitherOr, Padding, Column, Stack, Offset, Size, Row, Bin};
-use crate::PelicanUI;
+use crate::{PelicanUI, ElementID};
 
 use std::sync::mpsc::{self, Receiver};
 
 
 #[derive(Debug, Component)]
-pub struct TextInput(Column, Option<Text>, InputField, Option<Text>, Option<Text>);
+pub struct TextInput(Column, Option<Text>, InputField, Option<Text>, Option<Text>, #[skip] ElementID, #[skip] Option<bool>);
 
 impl TextInput {
     pub fn new(
@@ -28,8 +28,10 @@ impl TextInput {
             label.map(|text| Text::new(ctx, text, TextStyle::Heading, font_size.h5, Align::Left)),
             InputField::new(ctx, value, placeholder, icon_button),
             help_text.map(|t| Text::new(ctx, t, TextStyle::Secondary, font_size.sm, Align::Left)),
-            None
+            None,
             // SubText::new(ctx, help_text)
+            ElementID::new(),
+            None
         )
     }
 
@@ -47,12 +49,20 @@ impl TextInput {
 
     pub fn get_error(&mut self) -> &mut bool {self.2.error()}
     pub fn get_value(&mut self) -> &mut String {self.2.input()}
+
+    /// Id sent with `InputValidity`, e.g. for `Bumper::link`.
+    pub fn id(&self) -> ElementID {self.5}
 }
 
 impl OnEvent for TextInput {
-    fn on_event(&mut self, _ctx: &mut Context, event: &mut dyn Event) -> bool {
+    fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
         if let Some(TickEvent) = event.downcast_ref() {
-            *self.2.error() = self.4.is_some();
+            let error = self.4.is_some();
+            *self.2.error() = error;
+            if self.6 != Some(!error) {
+                self.6 = Some(!error);
+                ctx.trigger_event(InputValidity(self.5, !error));
+            }
         }
         true
     }
@@ -70,7 +80,7 @@ impl InputField {
     ) -> Self {
         let (background, outline) = InputState::Default.get_color(ctx);
         let content = InputContent::new(ctx, value, placeholder, icon_button);
-        let background = OutlinedRectangle::new(background, outline, 8.0, 1.0);
+        let background = OutlinedRectangle::new(ctx, background, outline, 8.0, 1.0);
 
         InputField(Stack(
             Offset::Start, Offset::Start, Size::fill(),
@@ -84,22 +94,28 @@ impl InputField {
 
     pub fn error(&mut self) -> &mut bool { &mut self.4 }
     pub fn input(&mut self) -> &mut String { &mut self.2.text().text().spans[0].text }
+
+    fn color(&mut self, ctx: &mut Context) {
+        let (background, outline) = self.3.get_color(ctx);
+        *self.1.background() = background;
+        *self.1.outline() = outline;
+        *self.2.focus() = self.3 == InputState::Focus;
+    }
 }
 
 impl OnEvent for InputField {
     fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
         if let Some(TickEvent) = event.downcast_ref() {
-            self.2.text().cursor().as_mut().map(|c| c.display(self.3 == InputState::Focus));
+            let focused = self.3 == InputState::Focus;
+            if let Some(cursor) = self.2.text().cursor() {cursor.blink(ctx, focused);}
             self.3 = match self.3 {
                 InputState::Default if self.4 => Some(InputState::Error),
                 InputState::Error if !self.4 => Some(InputState::Default),
                 _ => None
             }.unwrap_or(self.3);
-
-            let (background, outline) = self.3.get_color(ctx);
-            *self.1.background() = background;
-            *self.1.outline() = outline;
-            *self.2.focus() = self.3 == InputState::Focus;
+            self.color(ctx);
+        } else if event.downcast_ref::<ThemeChanged>().is_some() {
+            self.color(ctx);
         } else if let Some(SetActiveInput(s)) = event.downcast_ref::<SetActiveInput>() {
             *self.input() = s.to_string();
         } else if let Some(KeyboardActiveEvent(enabled)) = event.downcast_ref::<KeyboardActiveEvent>() {
@@ -0,0 +1,211 @@
+use rust_on_rails::prelude::*;
+use crate::components::scroll_view::{Scroller, ScrollAxis};
+use crate::events::ScrolledEvent;
+use crate::{ElementID, PelicanUI};
+
+const OVERSCAN_PX: f32 = 240.0; // distance above and below the viewport in which rows are kept alive
+
+pub type RowBuilder<D> = Box<dyn FnMut(&mut Context, usize) -> D>;
+pub type RowRecycler<D> = Box<dyn FnMut(&mut Context, usize, &mut D)>;
+
+/// Vertical list that only keeps the rows intersecting the viewport alive.
+/// Row heights are measured once built and cached, unmeasured rows use the estimate.
+///
+/// The list scrolls itself, put it in `Content::fixed` rather than a scrolling `Content`
+/// or it would be scrolled twice.
+pub struct VirtualList<D: Drawable + 'static>(
+    usize, f32, f32, RowBuilder<D>, Option<RowRecycler<D>>,
+    Vec<(usize, D)>, Vec<D>, Vec<Option<f32>>, Scroller, f32, ElementID, RowOffsets
+);
+// count, estimated height, spacing, builder, recycler, live rows, recycled rows, measured heights, scroller, viewport, id, row offsets
+
+impl<D: Drawable + 'static> VirtualList<D> {
+    pub fn new(
+        ctx: &mut Context,
+        count: usize,
+        estimate: f32,
+        spacing: f32,
+        builder: impl FnMut(&mut Context, usize) -> D + 'static,
+    ) -> Self {
+        // Until the first build the viewport is assumed to be the window so the first frame has rows
+        let viewport = ctx.get::<PelicanUI>().window().1;
+        let mut list = VirtualList(
+            count, estimate, spacing, Box::new(builder), None,
+            vec![], vec![], vec![None; count], Scroller::default(), viewport, ElementID::new(), RowOffsets::default()
+        );
+        list.reflow(0);
+        list.update_rows(ctx);
+        list
+    }
+
+    /// Rows scrolled out of view are handed back through `recycler` instead of being rebuilt.
+    pub fn recycled(
+        ctx: &mut Context,
+        count: usize,
+        estimate: f32,
+        spacing: f32,
+        builder: impl FnMut(&mut Context, usize) -> D + 'static,
+        recycler: impl FnMut(&mut Context, usize, &mut D) + 'static,
+    ) -> Self {
+        let mut list = VirtualList::new(ctx, count, estimate, spacing, builder);
+        list.4 = Some(Box::new(recycler));
+        list
+    }
+
+    pub fn set_count(&mut self, count: usize) {
+        let changed = self.0.min(count);
+        self.0 = count;
+        self.7.resize(count, None);
+        self.5.retain(|(i, _)| *i < count);
+        self.reflow(changed);
+    }
+
+    /// Drops every live row so they are rebuilt from the builder on the next tick.
+    pub fn invalidate(&mut self) {
+        self.5.clear();
+        self.6.clear();
+        self.7.iter_mut().for_each(|h| *h = None);
+        self.reflow(0);
+    }
+
+    pub fn id(&self) -> ElementID {self.10}
+    pub fn count(&self) -> usize {self.0}
+    pub fn scroller(&mut self) -> &mut Scroller {&mut self.8}
+    pub fn rows(&mut self) -> impl Iterator<Item = (usize, &mut D)> {self.5.iter_mut().map(|(i, r)| (*i, r))}
+
+    // Recomputes the offsets of the rows after `from`, whose height or count changed
+    fn reflow(&mut self, from: usize) {
+        let (heights, estimate) = (&self.7, self.1);
+        self.11.reflow(from, self.0, self.2, |i| heights[i].unwrap_or(estimate));
+    }
+
+    fn visible(&self) -> std::ops::Range<usize> {
+        let top = self.8.offset() - OVERSCAN_PX;
+        let bottom = self.8.offset() + self.9 + OVERSCAN_PX;
+        self.11.window(top, bottom, self.2)
+    }
+
+    fn update_rows(&mut self, ctx: &mut Context) {
+        let visible = self.visible();
+        let (kept, hidden): (Vec<_>, Vec<_>) = self.5.drain(..).partition(|(i, _)| visible.contains(i));
+        self.5 = kept;
+        if self.4.is_some() {self.6.extend(hidden.into_iter().map(|(_, row)| row));}
+
+        for index in visible {
+            if self.5.iter().any(|(i, _)| *i == index) {continue;}
+            let row = match (self.6.pop(), self.4.as_mut()) {
+                (Some(mut row), Some(recycler)) => {recycler(ctx, index, &mut row); row},
+                _ => (self.3)(ctx, index)
+            };
+            self.5.push((index, row));
+        }
+        self.5.sort_by_key(|(i, _)| *i);
+    }
+}
+
+impl<D: Drawable + 'static> OnEvent for VirtualList<D> {
+    fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
+        let start = self.8.offset();
+        if let Some(TickEvent) = event.downcast_ref() {
+            self.8.tick();
+            self.update_rows(ctx);
+        } else if let Some(event) = event.downcast_ref::<MouseEvent>() {
+            self.8.mouse(ScrollAxis::Vertical, event);
+        }
+
+        if self.8.offset() != start {
+            ctx.trigger_event(ScrolledEvent(self.10, self.8.offset()));
+        }
+        true
+    }
+}
+
+impl<D: Drawable + 'static> Component for VirtualList<D> {
+    fn children_mut(&mut self) -> Vec<&mut dyn Drawable> {self.5.iter_mut().map(|(_, r)| r as &mut dyn Drawable).collect()}
+    fn children(&self) -> Vec<&dyn Drawable> {self.5.iter().map(|(_, r)| r as &dyn Drawable).collect()}
+
+    fn request_size(&self, _ctx: &mut Context, children: Vec<SizeRequest>) -> SizeRequest {
+        let (min, max) = children.iter().fold((0.0f32, 0.0f32), |(min, max), i| (min.max(i.min_width()), max.max(i.max_width())));
+        SizeRequest::new(min, 0.0, max.max(min), self.11.total(self.2))
+    }
+
+    fn build(&mut self, _ctx: &mut Context, size: (f32, f32), children: Vec<SizeRequest>) -> Vec<Area> {
+        self.9 = size.1;
+        let sizes = self.5.iter().zip(children).map(|((index, _), i)| (*index, i.get((size.0, i.min_height())))).collect::<Vec<_>>();
+        let changed = sizes.iter().filter(|(index, s)| self.7[*index] != Some(s.1)).map(|(index, _)| *index).min();
+        sizes.iter().for_each(|(index, s)| self.7[*index] = Some(s.1));
+        if let Some(index) = changed {self.reflow(index);}
+
+        self.8.set_max(self.11.total(self.2) - size.1);
+        sizes.into_iter().map(|(index, size)| Area{offset: (0.0, self.11.start(index) - self.8.offset()), size}).collect()
+    }
+}
+
+impl<D: Drawable + 'static> std::fmt::Debug for VirtualList<D> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "VirtualList(...)")
+    }
+}
+
+// Start of every row followed by the end of the last row plus spacing, so lookups are binary searches
+#[derive(Debug, Default)]
+struct RowOffsets(Vec<f32>);
+
+impl RowOffsets {
+    fn reflow(&mut self, from: usize, count: usize, spacing: f32, height: impl Fn(usize) -> f32) {
+        self.0.resize(count + 1, 0.0);
+        for i in from.min(count)..count {
+            self.0[i + 1] = self.0[i] + height(i) + spacing;
+        }
+    }
+
+    fn start(&self, index: usize) -> f32 {self.0[index]}
+
+    fn total(&self, spacing: f32) -> f32 {(self.0[self.0.len() - 1] - spacing).max(0.0)}
+
+    // Rows that end at or below `top` and start at or above `bottom`
+    fn window(&self, top: f32, bottom: f32, spacing: f32) -> std::ops::Range<usize> {
+        let count = self.0.len() - 1;
+        let first = self.0[1..].partition_point(|end| end - spacing < top);
+        let last = self.0[..count].partition_point(|start| *start <= bottom);
+        first..last.max(first)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn offsets(heights: &[f32], spacing: f32) -> RowOffsets {
+        let mut offsets = RowOffsets::default();
+        offsets.reflow(0, heights.len(), spacing, |i| heights[i]);
+        offsets
+    }
+
+    #[test]
+    fn offsets_sum_heights_and_spacing() {
+        let offsets = offsets(&[10.0, 20.0, 30.0], 5.0);
+        assert_eq!((0..3).map(|i| offsets.start(i)).collect::<Vec<_>>(), vec![0.0, 15.0, 40.0]);
+        assert_eq!(offsets.total(5.0), 70.0);
+        assert_eq!(self::offsets(&[], 5.0).total(5.0), 0.0);
+    }
+
+    #[test]
+    fn reflow_only_moves_later_rows() {
+        let mut heights = vec![10.0; 4];
+        let mut offsets = offsets(&heights, 0.0);
+        heights[2] = 50.0;
+        offsets.reflow(2, 4, 0.0, |i| heights[i]);
+        assert_eq!((0..4).map(|i| offsets.start(i)).collect::<Vec<_>>(), vec![0.0, 10.0, 20.0, 70.0]);
+        assert_eq!(offsets.total(0.0), 80.0);
+    }
+
+    #[test]
+    fn window_holds_rows_intersecting_the_range() {
+        let offsets = offsets(&[10.0; 10], 0.0);
+        assert_eq!(offsets.window(25.0, 45.0, 0.0), 2..5);
+        assert_eq!(offsets.window(-100.0, -50.0, 0.0), 0..0);
+        assert_eq!(offsets.window(200.0, 300.0, 0.0), 10..10);
+        assert_eq!(offsets.window(-10.0, 1000.0, 0.0), 0..10);
+    }
+}
@@ -3,6 +3,7 @@ use crate::elements::text::{Text, TextStyle};
 use crate::elements::shapes::RoundedRectangle;
 use crate::components::avatar::{Avatar, AvatarContent};
 use crate::layout::{Column, Stack, Row, Padding, Offset, Size};
+use crate::events::ThemeChanged;
 use crate::PelicanUI;
 
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -124,8 +125,16 @@ impl MessageBubbles {
 }
 
 #[derive(Debug, Component)]
-pub struct MessageBubble(Stack, RoundedRectangle, Text);
-impl OnEvent for MessageBubble {}
+pub struct MessageBubble(Stack, RoundedRectangle, Text, #[skip] MessageType);
+
+impl OnEvent for MessageBubble {
+    fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if event.downcast_ref::<ThemeChanged>().is_some() {
+            self.1.shape().color = MessageBubble::style(ctx, self.3).0;
+        }
+        true
+    }
+}
 
 impl MessageBubble {
     pub fn new(
@@ -133,14 +142,8 @@ impl MessageBubble {
         message: &'static str,
         style: MessageType,
     ) -> Self {
-        let theme = &ctx.get::<PelicanUI>().theme;
-        let (colors, text_size) = (theme.colors, theme.fonts.size.md);
-        let (bg_color, text_style) = match style {
-            MessageType::You => (colors.brand.primary, TextStyle::White),
-            MessageType::Rooms => (colors.background.primary, TextStyle::White),
-            MessageType::Group => (colors.background.secondary, TextStyle::Primary),
-            MessageType::Contact => (colors.background.secondary, TextStyle::Primary),
-        };
+        let text_size = ctx.get::<PelicanUI>().theme.fonts.size.md;
+        let (bg_color, text_style) = MessageBubble::style(ctx, style);
 
         let (hp, vp) = (12.0, 12.0);
         let max_w = 200.0-(hp*2.0);
@@ -157,6 +160,16 @@ impl MessageBubble {
             Padding::default()
         );
 
-        MessageBubble(layout, background, content)
+        MessageBubble(layout, background, content, style)
+    }
+
+    fn style(ctx: &mut Context, style: MessageType) -> (Color, TextStyle) {
+        let colors = ctx.get::<PelicanUI>().theme.colors;
+        match style {
+            MessageType::You => (colors.brand.primary, TextStyle::White),
+            MessageType::Rooms => (colors.background.primary, TextStyle::Heading),
+            MessageType::Group => (colors.background.secondary, TextStyle::Primary),
+            MessageType::Contact => (colors.background.secondary, TextStyle::Primary),
+        }
     }
 }
\ No newline at end of file
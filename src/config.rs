@@ -1,4 +1,99 @@
 #[cfg(any(target_os = "ios", target_os = "android"))]
 pub const IS_MOBILE: bool = true;
 #[cfg(not(any(target_os = "ios", target_os = "android")))]
-pub const IS_MOBILE: bool = false;
\ No newline at end of file
+pub const IS_MOBILE: bool = false;
+
+pub const COMPACT_WIDTH: f32 = 600.0; // widths below use the mobile tab bar
+pub const WIDE_WIDTH: f32 = 1200.0; // widths from here on get the wide layout
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SizeClass {
+    #[default]
+    Compact,
+    Regular,
+    Wide,
+}
+
+impl SizeClass {
+    pub fn from_width(width: f32) -> Self {
+        match width {
+            w if w < COMPACT_WIDTH => SizeClass::Compact,
+            w if w < WIDE_WIDTH => SizeClass::Regular,
+            _ => SizeClass::Wide,
+        }
+    }
+
+    pub fn is_compact(&self) -> bool {*self == SizeClass::Compact}
+}
+
+/// Space at the screen edges covered by notches, rounded corners or the home indicator.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Insets {
+    pub top: f32,
+    pub bottom: f32,
+    pub left: f32,
+    pub right: f32,
+}
+
+impl Insets {
+    pub fn new(top: f32, bottom: f32, left: f32, right: f32) -> Self {
+        Insets{top, bottom, left, right}
+    }
+}
+
+/// Accessibility preferences, usually mirrored from the platform settings.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Accessibility {
+    /// High contrast palette and thicker outlines.
+    pub high_contrast: bool,
+    /// Transitions, sheets, the text cursor and the QR guide render in their final state.
+    pub reduce_motion: bool,
+}
+
+impl Accessibility {
+    pub fn stroke_scale(&self) -> f32 {if self.high_contrast {2.0} else {1.0}}
+}
+
+/// Reports the safe area insets of the platform for the current window size.
+pub trait SafeArea: std::fmt::Debug + 'static {
+    fn insets(&mut self, window: (f32, f32)) -> Insets;
+}
+
+/// Fixed insets for desktop and tests, landscape windows use the second set to simulate rotation.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct StaticSafeArea(pub Insets, pub Insets);
+
+impl StaticSafeArea {
+    pub fn new(insets: Insets) -> Self {StaticSafeArea(insets, insets)}
+
+    /// A phone with a `notch` at the top in portrait and a `home` indicator at the bottom.
+    pub fn notch(notch: f32, home: f32) -> Self {
+        StaticSafeArea(Insets::new(notch, home, 0.0, 0.0), Insets::new(0.0, home, notch, notch))
+    }
+}
+
+impl SafeArea for StaticSafeArea {
+    fn insets(&mut self, window: (f32, f32)) -> Insets {
+        if window.0 > window.1 {self.1} else {self.0}
+    }
+}
+
+#[cfg(target_os = "ios")]
+extern "C" {
+    fn get_safe_area_insets() -> *const f64;
+}
+
+/// Insets reported by UIKit.
+#[cfg(target_os = "ios")]
+#[derive(Debug, Default)]
+pub struct NativeSafeArea;
+
+#[cfg(target_os = "ios")]
+impl SafeArea for NativeSafeArea {
+    fn insets(&mut self, _window: (f32, f32)) -> Insets {
+        unsafe {
+            let ptr = get_safe_area_insets();
+            Insets::new(*ptr.add(0) as f32, *ptr.add(1) as f32, *ptr.add(2) as f32, *ptr.add(3) as f32)
+        }
+    }
+}
@@ -1,19 +1,48 @@
 use rust_on_rails::prelude::*;
 use crate::layout::Stack;
+use crate::events::AccessibilityChanged;
+use crate::config::Accessibility;
+use crate::PelicanUI;
 
 #[derive(Debug, Component)]
-pub struct OutlinedRectangle(Stack, RoundedRectangle, RoundedRectangle);
-impl OnEvent for OutlinedRectangle {}
+pub struct OutlinedRectangle(Stack, RoundedRectangle, RoundedRectangle, #[skip] f32);
+
+impl OnEvent for OutlinedRectangle {
+    fn on_event(&mut self, _ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if let Some(AccessibilityChanged(accessibility)) = event.downcast_ref::<AccessibilityChanged>() {
+            self.rescale(*accessibility);
+        }
+        true
+    }
+}
 
 impl OutlinedRectangle {
-    pub fn new(bg: Color, oc: Color, radius: f32, stroke: f32) -> Self {
+    /// `stroke` is scaled by the current `Accessibility::stroke_scale`.
+    pub fn new(ctx: &mut Context, bg: Color, oc: Color, radius: f32, stroke: f32) -> Self {
+        let scale = ctx.get::<PelicanUI>().accessibility().stroke_scale();
+        OutlinedRectangle::scaled(bg, oc, radius, stroke, scale)
+    }
+
+    pub(crate) fn scaled(bg: Color, oc: Color, radius: f32, stroke: f32, scale: f32) -> Self {
         OutlinedRectangle(
             Stack::default(),
             RoundedRectangle::new(0.0, radius, bg),
-            RoundedRectangle::new(stroke, radius, oc)
+            RoundedRectangle::new(stroke * scale, radius, oc),
+            stroke
         )
     }
 
+    /// Scales the stroke given at construction for new accessibility settings.
+    pub fn rescale(&mut self, accessibility: Accessibility) {
+        let stroke = self.3 * accessibility.stroke_scale();
+        if let ShapeType::RoundedRectangle(s, _, _) = &mut self.2.shape().shape {*s = stroke}
+    }
+
+    /// Width of the outline as drawn.
+    pub fn stroke(&mut self) -> f32 {
+        match self.2.shape().shape {ShapeType::RoundedRectangle(s, _, _) => s, _ => self.3}
+    }
+
     pub fn background(&mut self) -> &mut Color {&mut self.1.shape().color}
     pub fn outline(&mut self) -> &mut Color {&mut self.2.shape().color}
 }
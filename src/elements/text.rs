@@ -2,7 +2,10 @@ use rust_on_rails::prelude::*;
 use rust_on_rails::prelude::Text as BasicText;
 use crate::layout::{Stack, Offset, Size, Padding, Opt};
 use crate::elements::shapes::Rectangle;
+use crate::events::ThemeChanged;
+use crate::theme::colors::ColorResources;
 use crate::PelicanUI;
+use std::time::Instant;
 
 #[derive(Clone, Copy, Debug)]
 pub enum TextStyle {
@@ -18,43 +21,69 @@ pub enum TextStyle {
 impl TextStyle {
     pub fn get(&self, ctx: &mut Context) -> (Color, resources::Font) {
         let theme = &ctx.get::<PelicanUI>().theme;
+        let fonts = &theme.fonts.fonts;
+        let font = match self {
+            TextStyle::Heading => fonts.heading.clone(),
+            TextStyle::Keyboard => fonts.keyboard.clone(),
+            TextStyle::Label(_) => fonts.label.clone(),
+            _ => fonts.text.clone(),
+        };
+        (self.color(&theme.colors), font)
+    }
+
+    pub fn color(&self, colors: &ColorResources) -> Color {
         match self {
-            TextStyle::Heading => (theme.colors.text.heading, theme.fonts.fonts.heading.clone()),
-            TextStyle::Primary => (theme.colors.text.primary, theme.fonts.fonts.text.clone()),
-            TextStyle::Secondary => (theme.colors.text.secondary, theme.fonts.fonts.text.clone()),
-            TextStyle::Error => (theme.colors.text.danger, theme.fonts.fonts.text.clone()),
-            TextStyle::White => (theme.colors.text.heading, theme.fonts.fonts.text.clone()),
-            TextStyle::Keyboard => (theme.colors.text.heading, theme.fonts.fonts.keyboard.clone()),
-            TextStyle::Label(color) => (*color, theme.fonts.fonts.label.clone()),
+            TextStyle::Heading => colors.text.heading,
+            TextStyle::Primary => colors.text.primary,
+            TextStyle::Secondary => colors.text.secondary,
+            TextStyle::Error => colors.text.danger,
+            TextStyle::White => colors.shades.white,
+            TextStyle::Keyboard => colors.text.heading,
+            TextStyle::Label(color) => *color,
         }
     }
 }
 
+const BLINK: f32 = 0.5; // seconds the cursor stays on or off
+
 #[derive(Component, Debug)]
-pub struct TextCursor(Stack, Opt<Rectangle>);
+pub struct TextCursor(Stack, Opt<Rectangle>, #[skip] Option<Instant>);
 impl OnEvent for TextCursor {}
 impl TextCursor {
     pub fn new(ctx: &mut Context, style: TextStyle, size: f32) -> Self {
         let (color, _) = style.get(ctx);
         TextCursor(
             Stack(Offset::Start, Offset::End, Size::Static(2.0), Size::Static(size), Padding::default()), 
-            Opt::new(Rectangle::new(color), false)
+            Opt::new(Rectangle::new(color), false),
+            None
         )
     }
 
     pub fn display(&mut self, display: bool) {self.1.display(display)}
+
+    /// Shows the cursor while focused, blinking from when focus began unless motion is reduced.
+    pub fn blink(&mut self, ctx: &mut Context, focused: bool) {
+        let since = match (focused, self.2) {
+            (false, _) => {self.2 = None; return self.display(false)},
+            (true, None) => *self.2.insert(Instant::now()),
+            (true, Some(since)) => since,
+        };
+        let on = !ctx.get::<PelicanUI>().animate() || (since.elapsed().as_secs_f32() / BLINK) as u32 % 2 == 0;
+        self.display(on);
+    }
+    pub fn set_color(&mut self, color: Color) {self.1.inner().shape().color = color;}
     pub fn x_offset(&mut self) -> &mut Offset {&mut self.0.0}
     pub fn y_offset(&mut self) -> &mut Offset {&mut self.0.1}
 }
 
 #[derive(Component, Debug)]
-pub struct Text(Stack, BasicText, Option<TextCursor>);
+pub struct Text(Stack, BasicText, Option<TextCursor>, #[skip] TextStyle);
 
 impl Text {
     pub fn new(ctx: &mut Context, text: &'static str, style: TextStyle, size: f32, align: Align) -> Self {
         let (color, font) = style.get(ctx);
         let text = BasicText::new(vec![Span::new(text, size, size*1.25, font, color)], None, align, None);
-        Text(Stack(Offset::Start, Offset::Start, Size::Fit, Size::Fit, Padding::default()), text, None)
+        Text(Stack(Offset::Start, Offset::Start, Size::Fit, Size::Fit, Padding::default()), text, None, style)
     }
 
     pub fn new_with_cursor(ctx: &mut Context, text: &'static str, style: TextStyle, size: f32, align: Align) -> Self {
@@ -62,18 +91,27 @@ impl Text {
         let text = BasicText::new(vec![Span::new(text, size, size*1.25, font, color)], None, align, Some(Cursor::default()));
         Text(
             Stack(Offset::Start, Offset::Start, Size::Fit, Size::Fit, Padding::default()),
-            text, Some(TextCursor::new(ctx, style, size)),
+            text, Some(TextCursor::new(ctx, style, size)), style
         )
     }
 
     pub fn text(&mut self) -> &mut BasicText {&mut self.1}
     pub fn cursor(&mut self) -> &mut Option<TextCursor> {&mut self.2}
+
+    /// Recolors the text and cursor from the current theme.
+    pub fn color(&mut self, ctx: &mut Context) {
+        let (color, _) = self.3.get(ctx);
+        self.1.set_color(color);
+        if let Some(cursor) = &mut self.2 {cursor.set_color(color);}
+    }
 }
 
 
 impl OnEvent for Text {
     fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
-        if let Some(cursor) = &mut self.2 {
+        if event.downcast_ref::<ThemeChanged>().is_some() {
+            self.color(ctx);
+        } else if let Some(cursor) = &mut self.2 {
             if let Some(_) = event.downcast_ref::<TickEvent>() {
                 if let Some(cords) = self.1.cursor_action(ctx.as_canvas(), CursorAction::GetPosition) {
                     // println!("Got position: {:?}", cords);
@@ -1,5 +1,7 @@
 use rust_on_rails::prelude::*;
 use crate::{AppFlow, ElementID};
+use crate::config::{SizeClass, Insets, Accessibility};
+use crate::interface::Badge;
 
 #[derive(Debug, Clone)]
 pub struct NavigateEvent(pub Box<dyn AppFlow>);
@@ -9,6 +11,38 @@ impl Event for NavigateEvent {
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct ReplaceEvent(pub Box<dyn AppFlow>);
+impl Event for ReplaceEvent {
+    fn pass(self: Box<Self>, _ctx: &mut Context, children: Vec<((f32, f32), (f32, f32))>) -> Vec<Option<Box<dyn Event>>> {
+        children.into_iter().map(|_| Some(Box::new(*self.clone()) as Box<dyn Event>)).collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PopEvent;
+impl Event for PopEvent {
+    fn pass(self: Box<Self>, _ctx: &mut Context, children: Vec<((f32, f32), (f32, f32))>) -> Vec<Option<Box<dyn Event>>> {
+        children.into_iter().map(|_| Some(Box::new(*self) as Box<dyn Event>)).collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PopToRootEvent;
+impl Event for PopToRootEvent {
+    fn pass(self: Box<Self>, _ctx: &mut Context, children: Vec<((f32, f32), (f32, f32))>) -> Vec<Option<Box<dyn Event>>> {
+        children.into_iter().map(|_| Some(Box::new(*self) as Box<dyn Event>)).collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct TabSelected(pub usize);
+impl Event for TabSelected {
+    fn pass(self: Box<Self>, _ctx: &mut Context, children: Vec<((f32, f32), (f32, f32))>) -> Vec<Option<Box<dyn Event>>> {
+        children.into_iter().map(|_| Some(Box::new(*self) as Box<dyn Event>)).collect()
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct KeyboardActiveEvent(pub bool);
 impl Event for KeyboardActiveEvent {
@@ -71,4 +105,229 @@ impl Event for SetActiveInput {
     fn pass(self: Box<Self>, _ctx: &mut Context, children: Vec<((f32, f32), (f32, f32))>) -> Vec<Option<Box<dyn Event>>> {
         children.into_iter().map(|_| Some(Box::new(*self) as Box<dyn Event>)).collect()
     }
-}
\ No newline at end of file
+}
+#[derive(Debug, Clone, Copy)]
+pub struct ScrolledEvent(pub ElementID, pub f32);
+impl Event for ScrolledEvent {
+    fn pass(self: Box<Self>, _ctx: &mut Context, children: Vec<((f32, f32), (f32, f32))>) -> Vec<Option<Box<dyn Event>>> {
+        children.into_iter().map(|_| Some(Box::new(*self) as Box<dyn Event>)).collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ScrollToEvent(pub ElementID);
+impl Event for ScrollToEvent {
+    fn pass(self: Box<Self>, _ctx: &mut Context, children: Vec<((f32, f32), (f32, f32))>) -> Vec<Option<Box<dyn Event>>> {
+        children.into_iter().map(|_| Some(Box::new(*self) as Box<dyn Event>)).collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LocateEvent(pub (f32, f32), pub (f32, f32));
+// Absolute offset and size of the component receiving the event
+impl Event for LocateEvent {
+    fn pass(self: Box<Self>, _ctx: &mut Context, children: Vec<((f32, f32), (f32, f32))>) -> Vec<Option<Box<dyn Event>>> {
+        children.into_iter().map(|(offset, size)| {
+            let offset = (self.0.0+offset.0, self.0.1+offset.1);
+            Some(Box::new(LocateEvent(offset, size)) as Box<dyn Event>)
+        }).collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct OverlayDismissed(pub ElementID);
+impl Event for OverlayDismissed {
+    fn pass(self: Box<Self>, _ctx: &mut Context, children: Vec<((f32, f32), (f32, f32))>) -> Vec<Option<Box<dyn Event>>> {
+        children.into_iter().map(|_| Some(Box::new(*self) as Box<dyn Event>)).collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct SizeClassChanged(pub SizeClass);
+impl Event for SizeClassChanged {
+    fn pass(self: Box<Self>, _ctx: &mut Context, children: Vec<((f32, f32), (f32, f32))>) -> Vec<Option<Box<dyn Event>>> {
+        children.into_iter().map(|_| Some(Box::new(*self) as Box<dyn Event>)).collect()
+    }
+}
+
+/// The safe area insets changed, e.g. after the device rotated.
+#[derive(Debug, Clone, Copy)]
+pub struct SafeAreaChanged(pub Insets);
+impl Event for SafeAreaChanged {
+    fn pass(self: Box<Self>, _ctx: &mut Context, children: Vec<((f32, f32), (f32, f32))>) -> Vec<Option<Box<dyn Event>>> {
+        children.into_iter().map(|_| Some(Box::new(*self) as Box<dyn Event>)).collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct CloseDetailEvent;
+impl Event for CloseDetailEvent {
+    fn pass(self: Box<Self>, _ctx: &mut Context, children: Vec<((f32, f32), (f32, f32))>) -> Vec<Option<Box<dyn Event>>> {
+        children.into_iter().map(|_| Some(Box::new(*self) as Box<dyn Event>)).collect()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct OpenLinkEvent(pub String);
+impl Event for OpenLinkEvent {
+    fn pass(self: Box<Self>, _ctx: &mut Context, children: Vec<((f32, f32), (f32, f32))>) -> Vec<Option<Box<dyn Event>>> {
+        children.into_iter().map(|_| Some(Box::new(*self.clone()) as Box<dyn Event>)).collect()
+    }
+}
+
+/// No route matched a link given to `Interface::open` or sent as `OpenLinkEvent`.
+#[derive(Debug, Clone)]
+pub struct UnroutedLink(pub String);
+impl Event for UnroutedLink {
+    fn pass(self: Box<Self>, _ctx: &mut Context, children: Vec<((f32, f32), (f32, f32))>) -> Vec<Option<Box<dyn Event>>> {
+        children.into_iter().map(|_| Some(Box::new(*self.clone()) as Box<dyn Event>)).collect()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct OpenTabEvent(pub usize, pub Box<dyn AppFlow>);
+impl Event for OpenTabEvent {
+    fn pass(self: Box<Self>, _ctx: &mut Context, children: Vec<((f32, f32), (f32, f32))>) -> Vec<Option<Box<dyn Event>>> {
+        children.into_iter().map(|_| Some(Box::new(*self.clone()) as Box<dyn Event>)).collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct DialogChoice(pub ElementID, pub Option<usize>);
+impl Event for DialogChoice {
+    fn pass(self: Box<Self>, _ctx: &mut Context, children: Vec<((f32, f32), (f32, f32))>) -> Vec<Option<Box<dyn Event>>> {
+        children.into_iter().map(|_| Some(Box::new(*self) as Box<dyn Event>)).collect()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ShowToast {
+    pub text: &'static str,
+    pub icon: Option<&'static str>,
+    pub action: Option<(&'static str, ElementID)>,
+}
+
+impl ShowToast {
+    pub fn new(text: &'static str) -> Self {
+        ShowToast{text, icon: None, action: None}
+    }
+
+    pub fn icon(mut self, icon: &'static str) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Adds a button that triggers `ToastActionEvent(id)`.
+    pub fn action(mut self, label: &'static str, id: ElementID) -> Self {
+        self.action = Some((label, id));
+        self
+    }
+}
+
+impl Event for ShowToast {
+    fn pass(self: Box<Self>, _ctx: &mut Context, children: Vec<((f32, f32), (f32, f32))>) -> Vec<Option<Box<dyn Event>>> {
+        children.into_iter().map(|_| Some(Box::new(*self.clone()) as Box<dyn Event>)).collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ToastActionEvent(pub ElementID);
+impl Event for ToastActionEvent {
+    fn pass(self: Box<Self>, _ctx: &mut Context, children: Vec<((f32, f32), (f32, f32))>) -> Vec<Option<Box<dyn Event>>> {
+        children.into_iter().map(|_| Some(Box::new(*self) as Box<dyn Event>)).collect()
+    }
+}
+
+/// Shows `Badge` on the navigator button of tab `.0`, the profile tab comes after the navigation tabs.
+#[derive(Debug, Clone, Copy)]
+pub struct SetBadge(pub usize, pub Badge);
+impl Event for SetBadge {
+    fn pass(self: Box<Self>, _ctx: &mut Context, children: Vec<((f32, f32), (f32, f32))>) -> Vec<Option<Box<dyn Event>>> {
+        children.into_iter().map(|_| Some(Box::new(*self) as Box<dyn Event>)).collect()
+    }
+}
+
+/// Selects tab `.0` as if its navigator button was pressed.
+#[derive(Debug, Clone, Copy)]
+pub struct SelectTab(pub usize);
+impl Event for SelectTab {
+    fn pass(self: Box<Self>, _ctx: &mut Context, children: Vec<((f32, f32), (f32, f32))>) -> Vec<Option<Box<dyn Event>>> {
+        children.into_iter().map(|_| Some(Box::new(*self) as Box<dyn Event>)).collect()
+    }
+}
+
+/// Inserts a tab at `index`, or after the last tab, that opens `flow` when pressed.
+#[derive(Debug, Clone)]
+pub struct AddTab {
+    pub index: Option<usize>,
+    pub icon: &'static str,
+    pub label: &'static str,
+    pub flow: Box<dyn AppFlow>,
+}
+
+impl Event for AddTab {
+    fn pass(self: Box<Self>, _ctx: &mut Context, children: Vec<((f32, f32), (f32, f32))>) -> Vec<Option<Box<dyn Event>>> {
+        children.into_iter().map(|_| Some(Box::new(*self.clone()) as Box<dyn Event>)).collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct RemoveTab(pub usize);
+impl Event for RemoveTab {
+    fn pass(self: Box<Self>, _ctx: &mut Context, children: Vec<((f32, f32), (f32, f32))>) -> Vec<Option<Box<dyn Event>>> {
+        children.into_iter().map(|_| Some(Box::new(*self) as Box<dyn Event>)).collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct RenameTab(pub usize, pub &'static str);
+impl Event for RenameTab {
+    fn pass(self: Box<Self>, _ctx: &mut Context, children: Vec<((f32, f32), (f32, f32))>) -> Vec<Option<Box<dyn Event>>> {
+        children.into_iter().map(|_| Some(Box::new(*self) as Box<dyn Event>)).collect()
+    }
+}
+
+/// Moves the tab at `.0` to index `.1`.
+#[derive(Debug, Clone, Copy)]
+pub struct MoveTab(pub usize, pub usize);
+impl Event for MoveTab {
+    fn pass(self: Box<Self>, _ctx: &mut Context, children: Vec<((f32, f32), (f32, f32))>) -> Vec<Option<Box<dyn Event>>> {
+        children.into_iter().map(|_| Some(Box::new(*self) as Box<dyn Event>)).collect()
+    }
+}
+
+/// The text of a search header's field changed or its search button was pressed.
+#[derive(Debug, Clone)]
+pub struct SearchChanged(pub String);
+impl Event for SearchChanged {
+    fn pass(self: Box<Self>, _ctx: &mut Context, children: Vec<((f32, f32), (f32, f32))>) -> Vec<Option<Box<dyn Event>>> {
+        children.into_iter().map(|_| Some(Box::new(*self.clone()) as Box<dyn Event>)).collect()
+    }
+}
+
+/// Reports whether the input with the id is free of errors, sent whenever that changes.
+#[derive(Debug, Clone, Copy)]
+pub struct InputValidity(pub ElementID, pub bool);
+impl Event for InputValidity {
+    fn pass(self: Box<Self>, _ctx: &mut Context, children: Vec<((f32, f32), (f32, f32))>) -> Vec<Option<Box<dyn Event>>> {
+        children.into_iter().map(|_| Some(Box::new(*self) as Box<dyn Event>)).collect()
+    }
+}
+
+/// Accessibility preferences changed, sent after the `ThemeChanged` for the new palette.
+#[derive(Debug, Clone, Copy)]
+pub struct AccessibilityChanged(pub Accessibility);
+impl Event for AccessibilityChanged {
+    fn pass(self: Box<Self>, _ctx: &mut Context, children: Vec<((f32, f32), (f32, f32))>) -> Vec<Option<Box<dyn Event>>> {
+        children.into_iter().map(|_| Some(Box::new(*self) as Box<dyn Event>)).collect()
+    }
+}
+
+/// The active palette changed, themed components re-read their colors from `PelicanUI.theme`.
+#[derive(Debug, Clone, Copy)]
+pub struct ThemeChanged;
+impl Event for ThemeChanged {
+    fn pass(self: Box<Self>, _ctx: &mut Context, children: Vec<((f32, f32), (f32, f32))>) -> Vec<Option<Box<dyn Event>>> {
+        children.into_iter().map(|_| Some(Box::new(*self) as Box<dyn Event>)).collect()
+    }
+}
@@ -2,5 +2,19 @@ pub mod interface;
 pub use interface::*;
 pub mod navigation;
 pub use navigation::*;
+pub mod navigation_stack;
+pub use navigation_stack::*;
+pub mod page_transition;
+pub use page_transition::*;
 pub mod mobile_keyboard;
-pub use mobile_keyboard::*;
\ No newline at end of file
+pub use mobile_keyboard::*;
+pub mod overlay;
+pub use overlay::*;
+pub mod split_view;
+pub use split_view::*;
+pub mod router;
+pub use router::*;
+pub mod session;
+pub use session::*;
+pub mod toast;
+pub use toast::*;
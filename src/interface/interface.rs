@@ -1,18 +1,37 @@
 use rust_on_rails::prelude::*;
 use crate::elements::shapes::{Rectangle};
-use crate::events::{KeyboardActiveEvent, NavigateEvent};
-use crate::layout::{Column, Stack, Bin, Row, Padding, Offset, Size, Opt};
+use crate::events::{KeyboardActiveEvent, SizeClassChanged, SafeAreaChanged, TabSelected, OpenLinkEvent, OpenTabEvent, NavigateEvent};
+use crate::events::{SetBadge, SelectTab, AddTab, RemoveTab, RenameTab, MoveTab, LocateEvent, UnroutedLink, ThemeChanged};
+use crate::layout::{Column, Stack, Bin, Row, Padding, Offset, Size};
 use crate::components::avatar::AvatarContent;
+use crate::components::scroll_view::ScrollView;
+use crate::config::{SizeClass, Insets};
 use crate::PelicanUI;
 use crate::AppPage;
 use std::fmt::Debug;
+use std::rc::Rc;
+use std::cell::RefCell;
 
 use super::mobile_keyboard::MobileKeyboard;
-use super::navigation::{MobileNavigator, DesktopNavigator, Header, Bumper};
+use super::navigation::{MobileNavigator, DesktopNavigator, Header, Bumper, Badge};
+use super::overlay::OverlayLayer;
+use super::toast::ToastHost;
+use super::split_view::{SplitView, Divider};
+use super::navigation_stack::NavigationStack;
+use super::router::Router;
+use super::session::{NavigationState, FlowRegistry};
+
+const EASE: f32 = 0.25; // share of the remaining navigator slide covered per tick
+const CONTENT_WIDTH: f32 = 375.0; // widest page content, including its padding
+const CONTENT_PADDING: f32 = 24.0; // space on either side of page content
+
+type SharedCallback = Rc<RefCell<Box<dyn FnMut(&mut Context)>>>;
 
 #[derive(Debug, Component)]
-pub struct Interface (Stack, Option<MobileInterface>, Option<DesktopInterface>);
-impl OnEvent for Interface {}
+pub struct Interface (
+    Stack, Viewport, Option<MobileInterface>, Option<DesktopInterface>, ToastHost, OverlayLayer,
+    #[skip] Navigation, #[skip] SizeClass, #[skip] Divider, #[skip] Router, #[skip] Insets
+);
 
 impl Interface {
     pub fn new(
@@ -21,79 +40,376 @@ impl Interface {
         navigation: (usize, Vec<(&'static str, &'static str, Box<dyn FnMut(&mut Context)>)>),
         profile: (&'static str, AvatarContent, Box<dyn FnMut(&mut Context)>),
     ) -> Self {
-        let (mobile, desktop) = match crate::config::IS_MOBILE {
-            true => (Some(MobileInterface::new(ctx, start_page, navigation, profile)), None),
-            false => (None, Some(DesktopInterface::new(ctx, start_page, navigation, profile)))
+        let (size_class, insets) = (ctx.get::<PelicanUI>().size_class(), ctx.get::<PelicanUI>().insets());
+        let mut interface = Interface(
+            Stack::default(), Viewport, None, None, ToastHost::new(), OverlayLayer::new(),
+            Navigation::new(navigation.1, profile), size_class, Divider::default(), Router::default(), insets
+        );
+        let stack = NavigationStack::new(ctx, navigation.0, Box::new(start_page));
+        interface.layout(ctx, stack);
+        interface
+    }
+
+    /// Configures the divider between the main and detail panes on wide screens.
+    pub fn divider(mut self, ctx: &mut Context, divider: Divider) -> Self {
+        self.8 = divider;
+        if self.3.is_some() {
+            let stack = self.take_stack(ctx);
+            self.layout(ctx, stack);
+        }
+        self
+    }
+
+    /// Routes used for `open` and for `OpenLinkEvent`s at runtime.
+    pub fn router(mut self, router: Router) -> Self {
+        self.9 = router;
+        self
+    }
+
+    /// Starts the interface at the page `url` links to.
+    /// Keeps the start page and triggers `UnroutedLink` when no route matches.
+    pub fn open(mut self, ctx: &mut Context, url: &str) -> Self {
+        match self.9.resolve(url) {
+            Some((tab, flow)) => {
+                let stack = NavigationStack::new(ctx, tab, flow.get_page(ctx));
+                self.layout(ctx, stack);
+            },
+            None => ctx.trigger_event(UnroutedLink(url.to_string()))
+        }
+        self
+    }
+
+    /// The current tab and back stacks, ready to be saved with `NavigationState::save`.
+    pub fn snapshot(&self) -> NavigationState {
+        match (&self.2, &self.3) {
+            (Some(mobile), _) => mobile.1.snapshot(),
+            (_, Some(desktop)) => desktop.3.stack().snapshot(),
+            _ => NavigationState::default()
+        }
+    }
+
+    /// Restores a saved session on top of the start page, flows missing from `registry` are dropped.
+    pub fn restore(mut self, ctx: &mut Context, registry: &FlowRegistry, state: &NavigationState) -> Self {
+        // Tabs and the profile, which selects as the tab after the last one
+        let state = state.within(self.6.0.len() + 1);
+        let stack = self.take_stack(ctx).restore(ctx, &state, registry);
+        self.layout(ctx, stack);
+        self
+    }
+
+    // Pages ignore pointer and keyboard input while a modal overlay is shown
+    fn blocked(ctx: &mut Context, event: &mut dyn Event) -> bool {
+        ctx.get::<PelicanUI>().overlays.is_modal()
+            && (event.downcast_ref::<MouseEvent>().is_some() || event.downcast_ref::<KeyboardEvent>().is_some())
+    }
+
+    fn take_stack(&mut self, ctx: &mut Context) -> NavigationStack {
+        match (self.2.take(), self.3.take()) {
+            (Some(mobile), _) => mobile.1,
+            (_, Some(desktop)) => desktop.3.into_stack(ctx),
+            _ => unreachable!("Interface always holds a mobile or desktop interface")
+        }
+    }
+
+    fn layout(&mut self, ctx: &mut Context, stack: NavigationStack) {
+        let navigation = (stack.tab(), self.6.tabs());
+        let profile = self.6.profile();
+        match self.7.is_compact() {
+            true => {
+                self.3 = None;
+                self.2 = Some(MobileInterface::new(ctx, stack, navigation, profile));
+            },
+            false => {
+                self.2 = None;
+                self.3 = Some(DesktopInterface::new(ctx, stack, navigation, profile, self.8));
+            }
+        }
+        for (tab, badge) in self.6.2.clone().into_iter().enumerate().filter(|(_, b)| *b != Badge::None) {
+            match (&mut self.2, &mut self.3) {
+                (Some(mobile), _) => mobile.2.navigator().set_badge(ctx, tab, badge),
+                (_, Some(desktop)) => desktop.1.set_badge(ctx, tab, badge),
+                _ => {}
+            }
+        }
+    }
+
+    // Rebuilds the navigator after the tabs changed, `map` gives the new index of each old tab
+    fn retab(&mut self, ctx: &mut Context, map: impl Fn(usize) -> Option<usize>) {
+        let mut stack = self.take_stack(ctx);
+        let shown = stack.remap(map);
+        self.layout(ctx, stack);
+        if !shown {self.6.select(ctx, 0);}
+    }
+}
+
+impl OnEvent for Interface {
+    fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if let Some(TickEvent) = event.downcast_ref() {
+            let size_class = ctx.get::<PelicanUI>().size_class();
+            if size_class != self.7 {
+                self.7 = size_class;
+                if size_class.is_compact() != self.2.is_some() {
+                    // Move the current page and history over to the other navigation style
+                    let stack = self.take_stack(ctx);
+                    self.layout(ctx, stack);
+                }
+                ctx.trigger_event(SizeClassChanged(size_class));
+            }
+            let insets = ctx.get::<PelicanUI>().insets();
+            if insets != self.10 {
+                self.10 = insets;
+                ctx.trigger_event(SafeAreaChanged(insets));
+            }
+        } else if let Some(MouseEvent{state: MouseState::Pressed, position: Some(p)}) = event.downcast_ref::<MouseEvent>() {
+            // A press that dismisses an overlay does not reach the pages below it
+            if self.5.dismiss_outside(ctx, *p) {return false;}
+        } else if let Some(OpenLinkEvent(url)) = event.downcast_ref::<OpenLinkEvent>() {
+            match self.9.resolve(url) {
+                Some((tab, flow)) => ctx.trigger_event(OpenTabEvent(tab, flow)),
+                None => ctx.trigger_event(UnroutedLink(url.clone()))
+            }
+        } else if let Some(SetBadge(tab, badge)) = event.downcast_ref::<SetBadge>() {
+            if let Some(stored) = self.6.2.get_mut(*tab) {*stored = *badge;}
+        } else if let Some(SelectTab(tab)) = event.downcast_ref::<SelectTab>() {
+            self.6.select(ctx, *tab);
+        } else if let Some(AddTab{index, icon, label, flow}) = event.downcast_ref::<AddTab>() {
+            let index = index.unwrap_or(self.6.0.len()).min(self.6.0.len());
+            let flow = flow.clone();
+            let callback: Box<dyn FnMut(&mut Context)> = Box::new(move |ctx: &mut Context| ctx.trigger_event(NavigateEvent(flow.clone())));
+            self.6.0.insert(index, (*icon, *label, Rc::new(RefCell::new(callback))));
+            self.6.2.insert(index, Badge::None);
+            self.retab(ctx, |tab| Some(if tab >= index {tab + 1} else {tab}));
+        } else if let Some(RemoveTab(index)) = event.downcast_ref::<RemoveTab>() {
+            // The navigators need at least one tab
+            let index = *index;
+            if index < self.6.0.len() && self.6.0.len() > 1 {
+                self.6.0.remove(index);
+                self.6.2.remove(index);
+                self.retab(ctx, |tab| match tab.cmp(&index) {
+                    std::cmp::Ordering::Less => Some(tab),
+                    std::cmp::Ordering::Equal => None,
+                    std::cmp::Ordering::Greater => Some(tab - 1),
+                });
+            }
+        } else if let Some(RenameTab(index, label)) = event.downcast_ref::<RenameTab>() {
+            if let Some(tab) = self.6.0.get_mut(*index) {
+                tab.1 = *label;
+                self.retab(ctx, Some);
+            }
+        } else if let Some(MoveTab(from, to)) = event.downcast_ref::<MoveTab>() {
+            let tabs = self.6.0.len();
+            if *from < tabs {
+                let to = (*to).min(tabs - 1);
+                let mut order: Vec<usize> = (0..tabs).collect();
+                order.insert(to, order.remove(*from));
+                self.6.0.insert(to, self.6.0.remove(*from));
+                self.6.2.insert(to, self.6.2.remove(*from));
+                self.retab(ctx, |tab| Some(order.iter().position(|t| *t == tab).unwrap_or(tab)));
+            }
+        }
+        true
+    }
+}
+
+// Tab callbacks are shared so both navigation styles can be rebuilt from them,
+// each one reports its tab index before running. Badges are kept for every tab and the profile.
+struct Navigation(Vec<(&'static str, &'static str, SharedCallback)>, (&'static str, AvatarContent, SharedCallback), Vec<Badge>);
+
+impl Navigation {
+    fn new(
+        tabs: Vec<(&'static str, &'static str, Box<dyn FnMut(&mut Context)>)>,
+        profile: (&'static str, AvatarContent, Box<dyn FnMut(&mut Context)>),
+    ) -> Self {
+        let badges = vec![Badge::None; tabs.len() + 1];
+        Navigation(
+            tabs.into_iter().map(|(i, n, c)| (i, n, Rc::new(RefCell::new(c)))).collect(),
+            (profile.0, profile.1, Rc::new(RefCell::new(profile.2))),
+            badges
+        )
+    }
+
+    // Runs the tab's callback as if its button was pressed
+    fn select(&self, ctx: &mut Context, tab: usize) {
+        let callback = match self.0.get(tab) {
+            Some((_, _, callback)) => callback,
+            None if tab == self.0.len() => &self.1.2,
+            None => return
         };
-        Interface(Stack::default(), mobile, desktop)
+        (Navigation::callback(tab, callback))(ctx)
+    }
+
+    fn tabs(&self) -> Vec<(&'static str, &'static str, Box<dyn FnMut(&mut Context)>)> {
+        self.0.iter().enumerate().map(|(tab, (i, n, c))| (*i, *n, Navigation::callback(tab, c))).collect()
+    }
+
+    fn profile(&self) -> (&'static str, AvatarContent, Box<dyn FnMut(&mut Context)>) {
+        (self.1.0, self.1.1.clone(), Navigation::callback(self.0.len(), &self.1.2))
+    }
+
+    fn callback(tab: usize, callback: &SharedCallback) -> Box<dyn FnMut(&mut Context)> {
+        let callback = callback.clone();
+        Box::new(move |ctx: &mut Context| {
+            ctx.trigger_event(TabSelected(tab));
+            (callback.borrow_mut())(ctx)
+        })
+    }
+}
+
+impl Debug for Navigation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Navigation(...)")
+    }
+}
+
+// Reports the window size to PelicanUI so the size class follows the actual window
+#[derive(Debug)]
+struct Viewport;
+impl OnEvent for Viewport {}
+
+impl Component for Viewport {
+    fn children_mut(&mut self) -> Vec<&mut dyn Drawable> {vec![]}
+    fn children(&self) -> Vec<&dyn Drawable> {vec![]}
+    fn request_size(&self, _ctx: &mut Context, _children: Vec<SizeRequest>) -> SizeRequest {
+        SizeRequest::fill()
+    }
+    fn build(&mut self, ctx: &mut Context, size: (f32, f32), _children: Vec<SizeRequest>) -> Vec<Area> {
+        ctx.get::<PelicanUI>().resize(size);
+        vec![]
     }
 }
 
 #[derive(Debug, Component)]
-struct MobileInterface (Column, Box<dyn AppPage>, Opt<MobileNavigator>, Option<MobileKeyboard>);
+struct MobileInterface (Column, NavigationStack, NavigatorSlot, Option<MobileKeyboard>);
 
 impl MobileInterface {
     pub fn new(
         ctx: &mut Context, 
-        start_page: impl AppPage,
+        stack: NavigationStack,
         navigation: (usize, Vec<(&'static str, &'static str, Box<dyn FnMut(&mut Context)>)>),
         profile: (&'static str, AvatarContent, Box<dyn FnMut(&mut Context)>),
     ) -> Self {
-        let navigator = MobileNavigator::new(ctx, navigation, profile);
-        #[cfg(target_os = "ios")] // move to rust_on_rails layer
-        let insets = safe_area_insets();
-        #[cfg(not(target_os = "ios"))]
-        let insets = (0., 0., 0., 0.);
-        MobileInterface(
-            Column::new(0.0, Offset::Center, Size::Fit, Padding(0.0, insets.0, 0.0, insets.1)), 
-            Box::new(start_page), Opt::new(navigator, false), None,
-        )
+        let navigator = NavigatorSlot::new(MobileNavigator::new(ctx, navigation, profile), stack.has_nav());
+        let insets = ctx.get::<PelicanUI>().insets();
+        MobileInterface(MobileInterface::layout(insets), stack, navigator, None)
+    }
+
+    // Keeps the header, bumper and navigator clear of notches and the home indicator
+    fn layout(insets: Insets) -> Column {
+        Column::new(0.0, Offset::Center, Size::Fit, Padding(insets.left, insets.top, insets.right, insets.bottom))
     }
 }
 
 impl OnEvent for MobileInterface {
     fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if Interface::blocked(ctx, event) {return false;}
         if let Some(_event) = event.downcast_ref::<TickEvent>() {
-            // self.2.display(self.1.navigator_status());
+            let shown = self.1.has_nav() && self.3.is_none();
+            self.2.show(ctx, shown);
+        } else if let Some(SafeAreaChanged(insets)) = event.downcast_ref::<SafeAreaChanged>() {
+            self.0 = MobileInterface::layout(*insets);
         } else if let Some(KeyboardActiveEvent(enabled)) = event.downcast_ref::<KeyboardActiveEvent>() {
             self.3 = match enabled {
                 true => Some(MobileKeyboard::new(ctx)),
                 false => None
             };
-        } else if let Some(NavigateEvent(page)) = event.downcast_ref::<NavigateEvent>() {
-            self.1 = page.get_page(ctx);
         }
         true
     }
 }
 
+// Slides the tab bar below the bottom edge while the page or the keyboard hides it
+#[derive(Debug)]
+struct NavigatorSlot(MobileNavigator, f32);
+
+impl NavigatorSlot {
+    fn new(navigator: MobileNavigator, shown: bool) -> Self {
+        NavigatorSlot(navigator, if shown {1.0} else {0.0})
+    }
+
+    fn navigator(&mut self) -> &mut MobileNavigator {&mut self.0}
+
+    fn show(&mut self, ctx: &mut Context, shown: bool) {
+        let target = if shown {1.0} else {0.0};
+        if self.1 != target {ctx.get::<PelicanUI>().overlays.relocate();}
+        self.1 = match ctx.get::<PelicanUI>().animate() {
+            true if (target - self.1).abs() > 0.01 => self.1 + (target - self.1) * EASE,
+            _ => target
+        };
+    }
+}
+
+impl OnEvent for NavigatorSlot {
+    fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
+        // A hidden navigator still follows tab selection and badges
+        if self.1 == 0.0 && event.downcast_ref::<MouseEvent>().is_none() && event.downcast_ref::<LocateEvent>().is_none() {
+            self.0.on_event(ctx, event);
+        }
+        true
+    }
+}
+
+impl Component for NavigatorSlot {
+    fn children_mut(&mut self) -> Vec<&mut dyn Drawable> {
+        if self.1 > 0.0 {vec![&mut self.0]} else {vec![]}
+    }
+
+    fn children(&self) -> Vec<&dyn Drawable> {
+        if self.1 > 0.0 {vec![&self.0]} else {vec![]}
+    }
+
+    fn request_size(&self, _ctx: &mut Context, children: Vec<SizeRequest>) -> SizeRequest {
+        match children.first() {
+            Some(navigator) => {
+                let height = navigator.min_height() * self.1;
+                SizeRequest::new(navigator.min_width(), height, navigator.max_width(), height)
+            },
+            None => SizeRequest::new(0.0, 0.0, f32::MAX, 0.0)
+        }
+    }
+
+    fn build(&mut self, _ctx: &mut Context, size: (f32, f32), children: Vec<SizeRequest>) -> Vec<Area> {
+        children.into_iter().map(|navigator| {
+            Area{offset: (0.0, 0.0), size: navigator.get((size.0, navigator.min_height()))}
+        }).collect()
+    }
+}
+
 #[derive(Debug, Component)]
-struct DesktopInterface (Row, DesktopNavigator, Bin<Stack, Rectangle>, Box<dyn AppPage>);
+struct DesktopInterface (Row, DesktopNavigator, Bin<Stack, Rectangle>, SplitView);
 
 impl DesktopInterface {
     pub fn new(
         ctx: &mut Context, 
-        start_page: impl AppPage, 
+        stack: NavigationStack,
         navigation: (usize, Vec<(&'static str, &'static str, Box<dyn FnMut(&mut Context)>)>),
         profile: (&'static str, AvatarContent, Box<dyn FnMut(&mut Context)>),
+        divider: Divider,
     ) -> Self {
         let color = ctx.get::<PelicanUI>().theme.colors.outline.secondary;
+        let insets = ctx.get::<PelicanUI>().insets();
         DesktopInterface(
-            Row(0.0, Offset::Start, Size::Fit, Padding::default()),
+            DesktopInterface::layout(insets),
             DesktopNavigator::new(ctx, navigation, profile), 
             Bin (
                 Stack(Offset::default(), Offset::default(), Size::Static(1.0),  Size::Fit, Padding::default()), 
                 Rectangle::new(color)
             ),
-            Box::new(start_page)
+            SplitView::new(ctx, stack, divider)
         )
     }
+
+    fn layout(insets: Insets) -> Row {
+        Row(0.0, Offset::Start, Size::Fit, Padding(insets.left, insets.top, insets.right, insets.bottom))
+    }
 }
 
 impl OnEvent for DesktopInterface {
     fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
-        if let Some(NavigateEvent(page)) = event.downcast_ref::<NavigateEvent>() {
-            self.3 = page.get_page(ctx);
+        if Interface::blocked(ctx, event) {return false;}
+        if let Some(SafeAreaChanged(insets)) = event.downcast_ref::<SafeAreaChanged>() {
+            self.0 = DesktopInterface::layout(*insets);
+        } else if event.downcast_ref::<ThemeChanged>().is_some() {
+            self.2.inner().shape().color = ctx.get::<PelicanUI>().theme.colors.outline.secondary;
         }
         true
     }
@@ -104,7 +420,8 @@ pub struct Page (Column, Header, Content, Option<Bumper>, #[skip] bool);
 impl OnEvent for Page {}
 
 impl Page {
-    pub fn new(header: Header, content: Content, bumper: Option<Bumper>, has_nav: bool) -> Self {
+    pub fn new(mut header: Header, mut content: Content, bumper: Option<Bumper>, has_nav: bool) -> Self {
+        header.follow(content.scroll_view().id());
         let width = Size::custom(move |widths: Vec<(f32, f32)>|(widths[1].0, f32::MAX));
         Page(
             Column::new(12.0, Offset::Center, width, Padding::default()),
@@ -121,59 +438,37 @@ impl Page {
     pub fn navigator_status(&self) -> bool {self.4}
 }
 
+impl AppPage for Page {
+    fn has_nav(&self) -> bool {self.4}
+}
+
 #[derive(Debug, Component)]
-pub struct Content (Stack, ContentChildren);
+pub struct Content (Stack, ScrollView);
+impl OnEvent for Content {}
 
 impl Content {
     pub fn new(offset: Offset, content: Vec<Box<dyn Drawable>>) -> Self {
-        let width = Size::custom(move |widths: Vec<(f32, f32)>|(widths[0].0.min(375.0), 375.0));
+        let width = Size::custom(move |widths: Vec<(f32, f32)>|(widths[0].0.min(CONTENT_WIDTH), CONTENT_WIDTH));
         let height = Size::custom(move |_: Vec<(f32, f32)>|(0.0, f32::MAX));
         Content(
-            Stack(Offset::Center, offset, width, height, Padding(24.0, 0.0, 24.0, 0.0)),
-            ContentChildren::new(content),
+            Stack(Offset::Center, offset, width, height, Padding(CONTENT_PADDING, 0.0, CONTENT_PADDING, 0.0)),
+            ScrollView::vertical(24.0, content),
         )
     }
     
-    pub fn items(&mut self) -> &mut Vec<Box<dyn Drawable>> {&mut self.1.1}
-}
-
-impl OnEvent for Content {
-    fn on_event(&mut self, _ctx: &mut Context, event: &mut dyn Event) -> bool {
-        if let Some(event) = event.downcast_ref::<MouseEvent>() {
-            if let MouseEvent{state: MouseState::Scroll(_, y), ..} = event {
-                *self.1.0.scroll() += y;
-                *self.1.0.scroll() = self.1.0.scroll().clamp(0.0, 100.); // 100 = content height
-            }
-        }
-        true
+    /// Content that does not scroll, for pages built around a child that scrolls itself like `VirtualList`.
+    pub fn fixed(offset: Offset, content: Vec<Box<dyn Drawable>>) -> Self {
+        let mut content = Content::new(offset, content);
+        content.1.scroller().lock(true);
+        content
     }
-}
 
-#[derive(Debug, Component)]
-struct ContentChildren (Column, Vec<Box<dyn Drawable>>);
-impl OnEvent for ContentChildren {}
+    pub fn items(&mut self) -> &mut Vec<Box<dyn Drawable>> {self.1.items()}
+    pub fn scroll_view(&mut self) -> &mut ScrollView {&mut self.1}
 
-impl ContentChildren {
-    pub fn new(content: Vec<Box<dyn Drawable>>) -> Self {
-        ContentChildren(Column::center(24.0), content)
-    }
-}
-
-
-#[cfg(target_os = "ios")]
-extern "C" {
-    fn get_safe_area_insets() -> *const f64;
-}
-
-#[cfg(target_os = "ios")]
-pub fn safe_area_insets() -> (f32, f32, f32, f32) {
-    unsafe {
-        let ptr = get_safe_area_insets();
-        (
-            *ptr.add(0) as f32, // top
-            *ptr.add(1) as f32, // bottom
-            *ptr.add(2) as f32, // left
-            *ptr.add(3) as f32, // right
-        )
+    /// Width left for page content in the current window, inside the content padding.
+    pub fn width(ctx: &mut Context) -> f32 {
+        let (window, insets) = (ctx.get::<PelicanUI>().window().0, ctx.get::<PelicanUI>().insets());
+        ((window - insets.left - insets.right).min(CONTENT_WIDTH) - CONTENT_PADDING * 2.0).max(0.0)
     }
 }
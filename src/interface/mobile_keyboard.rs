@@ -408,7 +408,7 @@ impl KeyCharacter {
 
     pub fn paginator(ctx: &mut Context, page: u32) -> Self {
         let size = ctx.get::<PelicanUI>().theme.fonts.size.h2;
-        let (highlight, dim) = (TextStyle::White, TextStyle::Secondary);
+        let (highlight, dim) = (TextStyle::Heading, TextStyle::Secondary);
 
         let styles = match page {
             0 => (highlight, dim, dim),
@@ -1,13 +1,22 @@
 use rust_on_rails::prelude::*;
-use crate::events::NavigatorSelect;
+use crate::events::{NavigatorSelect, OpenTabEvent, TabSelected, SetBadge, LocateEvent, ScrolledEvent, SearchChanged, InputValidity, ThemeChanged};
 use crate::elements::images::Brand;
 use crate::elements::text::{Text, TextStyle};
-use crate::elements::shapes::Rectangle;
+use crate::elements::shapes::{Rectangle, RoundedRectangle, Circle};
 use crate::components::button::{Button, IconButton, ButtonState};
 use crate::components::avatar::{AvatarContent, AvatarRow};
-use crate::layout::{Column, Stack, Bin, Row, Padding, Offset, Size};
+use crate::components::text_input::TextInput;
+use crate::components::messages::Profile;
+use crate::layout::{Column, Stack, Bin, Row, Padding, Offset, Size, Opt};
 use crate::{PelicanUI, ElementID};
 
+use std::collections::HashSet;
+use std::sync::mpsc::{self, Receiver};
+
+const COLLAPSE: f32 = 40.0; // content scroll offset at which a large title moves into the bar
+const COMPOSER_LINES: f32 = 5.0; // lines of text the chat composer grows to as they are typed
+const COMPOSER_PADDING: f32 = 32.0; // vertical padding around the text of an input field
+
 #[derive(Debug, Component)]
 pub struct MobileNavigator(Row, Vec<NavigationButton>);
 
@@ -39,6 +48,22 @@ impl MobileNavigator {
 
         MobileNavigator(Row(48.0, Offset::Center, Size::Fit, Padding(0.0, 8.0, 0.0, 8.0)), tabs)
     }
+
+    /// Index of the selected tab, the profile tab comes after the navigation tabs.
+    pub fn selected(&mut self) -> Option<usize> {
+        self.1.iter_mut().position(|button| button.state() == Some(ButtonState::Selected))
+    }
+
+    pub fn select(&mut self, ctx: &mut Context, tab: usize) {
+        self.1.iter_mut().enumerate().for_each(|(i, button)| {
+            let state = if i == tab {ButtonState::Selected} else {ButtonState::UnSelected};
+            button.set_state(ctx, state);
+        });
+    }
+
+    pub fn set_badge(&mut self, ctx: &mut Context, tab: usize, badge: Badge) {
+        if let Some(button) = self.1.get_mut(tab) {button.set_badge(ctx, badge);}
+    }
 }
 
 impl OnEvent for MobileNavigator {
@@ -46,9 +71,20 @@ impl OnEvent for MobileNavigator {
         if let Some(NavigatorSelect(id)) = event.downcast_ref::<NavigatorSelect>() {
             println!("Navigator selected");
             self.1.iter_mut().for_each(|button| {
-                *button.1.as_mut().unwrap().status() = if button.id() == *id {ButtonState::Selected} else {ButtonState::UnSelected};
-                button.1.as_mut().unwrap().color(ctx);
+                let state = if button.id() == *id {ButtonState::Selected} else {ButtonState::UnSelected};
+                button.set_state(ctx, state);
             });
+        } else if let Some(OpenTabEvent(tab, _)) = event.downcast_ref::<OpenTabEvent>() {
+            self.select(ctx, *tab);
+        } else if let Some(TabSelected(tab)) = event.downcast_ref::<TabSelected>() {
+            self.select(ctx, *tab);
+        } else if let Some(SetBadge(tab, badge)) = event.downcast_ref::<SetBadge>() {
+            self.set_badge(ctx, *tab, *badge);
+        } else if let Some(LocateEvent(offset, _)) = event.downcast_ref::<LocateEvent>() {
+            ctx.get::<PelicanUI>().overlays.reserve(offset.1);
+        } else if event.downcast_ref::<ThemeChanged>().is_some() {
+            // Also reached while the navigator is hidden and its buttons get no events
+            self.1.iter_mut().for_each(|button| button.color(ctx));
         }
         true
     }
@@ -96,6 +132,33 @@ impl DesktopNavigator {
             NavigationButton::new(profile_id, Some(pb), None)
         )
     }
+
+    /// Index of the selected tab, the profile tab comes after the navigation tabs.
+    pub fn selected(&mut self) -> Option<usize> {
+        let tabs = self.2.buttons().len();
+        match self.2.buttons().iter_mut().position(|button| button.state() == Some(ButtonState::Selected)) {
+            Some(index) => Some(index),
+            None => (self.4.state() == Some(ButtonState::Selected)).then_some(tabs)
+        }
+    }
+
+    pub fn select(&mut self, ctx: &mut Context, tab: usize) {
+        let id = self.2.buttons().get(tab).map(|button| button.id()).unwrap_or(self.4.id());
+        let mut buttons: Vec<&mut NavigationButton> = self.2.buttons().iter_mut().collect();
+        buttons.push(&mut self.4);
+        buttons.iter_mut().for_each(|button| {
+            let state = if button.id() == id {ButtonState::Selected} else {ButtonState::Default};
+            button.set_state(ctx, state);
+        });
+    }
+
+    pub fn set_badge(&mut self, ctx: &mut Context, tab: usize, badge: Badge) {
+        match self.2.buttons().get_mut(tab) {
+            Some(button) => button.set_badge(ctx, badge),
+            None if tab == self.2.buttons().len() => self.4.set_badge(ctx, badge),
+            None => {}
+        }
+    }
 }
 
 impl OnEvent for DesktopNavigator {
@@ -105,9 +168,17 @@ impl OnEvent for DesktopNavigator {
             let mut buttons: Vec<&mut NavigationButton> = self.2.buttons().iter_mut().map(|btn| btn).collect();
             buttons.push(&mut self.4);
             buttons.iter_mut().for_each(|button| {
-                *button.1.as_mut().unwrap().status() = if button.id() == *id {ButtonState::Selected} else {ButtonState::Default};
-                button.1.as_mut().unwrap().color(ctx);
+                let state = if button.id() == *id {ButtonState::Selected} else {ButtonState::Default};
+                button.set_state(ctx, state);
             });
+        } else if let Some(OpenTabEvent(tab, _)) = event.downcast_ref::<OpenTabEvent>() {
+            self.select(ctx, *tab);
+        } else if let Some(TabSelected(tab)) = event.downcast_ref::<TabSelected>() {
+            self.select(ctx, *tab);
+        } else if let Some(SetBadge(tab, badge)) = event.downcast_ref::<SetBadge>() {
+            self.set_badge(ctx, *tab, *badge);
+        } else if event.downcast_ref::<ThemeChanged>().is_some() {
+            self.3.inner().shape().color = ctx.get::<PelicanUI>().theme.colors.shades.transparent;
         }
         true
     }
@@ -126,29 +197,70 @@ impl ButtonColumn {
 }
 
 #[derive(Debug, Component)]
-pub struct NavigationButton(Stack, Option<Button>, Option<IconButton>, #[skip] ElementID);
-impl OnEvent for NavigationButton {}
+pub struct NavigationButton(Stack, Option<Button>, Option<IconButton>, Option<BadgeView>, #[skip] ElementID, #[skip] Badge);
+
+impl OnEvent for NavigationButton {
+    fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if event.downcast_ref::<ThemeChanged>().is_some() {
+            self.3 = BadgeView::new(ctx, self.5);
+        }
+        true
+    }
+}
 
 impl NavigationButton {
     pub fn new(id: ElementID, button: Option<Button>, icon_button: Option<IconButton>) -> Self {
-        NavigationButton(Stack::default(), button, icon_button, id)
+        // Sized by the button so the badge can sit on its corner
+        let layout = Stack(
+            Offset::Center, Offset::Center,
+            Size::custom(|widths: Vec<(f32, f32)>| widths[0]),
+            Size::custom(|heights: Vec<(f32, f32)>| heights[0]),
+            Padding::default()
+        );
+        NavigationButton(layout, button, icon_button, None, id, Badge::None)
+    }
+
+    pub fn id(&self) -> ElementID {self.4}
+    pub fn badge(&self) -> Badge {self.5}
+
+    pub fn set_badge(&mut self, ctx: &mut Context, badge: Badge) {
+        self.5 = badge;
+        self.3 = BadgeView::new(ctx, badge);
+    }
+
+    pub fn state(&mut self) -> Option<ButtonState> {
+        match (&mut self.1, &mut self.2) {
+            (Some(button), _) => Some(*button.status()),
+            (_, Some(icon_button)) => Some(*icon_button.status()),
+            _ => None
+        }
     }
 
-    pub fn id(&self) -> ElementID {self.3}
+    /// Recolors the button and badge in their current state.
+    pub fn color(&mut self, ctx: &mut Context) {
+        if let Some(state) = self.state() {self.set_state(ctx, state);}
+        self.3 = BadgeView::new(ctx, self.5);
+    }
+
+    pub fn set_state(&mut self, ctx: &mut Context, state: ButtonState) {
+        if let Some(button) = &mut self.1 {
+            *button.status() = state;
+            button.color(ctx);
+        }
+        if let Some(icon_button) = &mut self.2 {
+            *icon_button.status() = state;
+            icon_button.color(ctx, state);
+        }
+    }
 }
 
+/// Page header. `large` headers show a big title that collapses into the bar once the page's content scrolls.
 #[derive(Debug, Component)]
-pub struct Header(Row, HeaderIcon, HeaderContent, HeaderIcon);
-impl OnEvent for Header {}
+pub struct Header(Column, HeaderBar, Option<LargeTitle>, Option<SearchBar>, #[skip] Option<ElementID>);
 
 impl Header {
     pub fn home(ctx: &mut Context, title: &'static str) -> Self {
-        Header(
-            Row(16.0, Offset::Center, Size::Fit, Padding(24.0, 16.0, 24.0, 16.0)),
-            HeaderIcon::new(None), 
-            HeaderContent::home(ctx, title),
-            HeaderIcon::new(None)
-        )
+        Header::new(HeaderBar::new(None, HeaderContent::home(ctx, title), None), None, None)
     }
 
     pub fn stack(
@@ -157,31 +269,96 @@ impl Header {
         title: &'static str, 
         right: Option<IconButton>
     ) -> Self {
-        Header(
-            Row(16.0, Offset::Center, Size::Fit, Padding(24.0, 16.0, 24.0, 16.0)),
-            HeaderIcon::new(left), 
-            HeaderContent::stack(ctx, title), 
-            HeaderIcon::new(right)
-        )
+        Header::new(HeaderBar::new(left, HeaderContent::stack(ctx, title), right), None, None)
     }
 
+    /// Titled with the participants' names, e.g. "Ella", "Ella & Sam" or "Ella, Sam & 2 more".
     pub fn chat(
         ctx: &mut Context, 
         left: Option<IconButton>,
         right: Option<IconButton>,
-        avatars: Vec<AvatarContent>,
+        profiles: Vec<Profile>,
+    ) -> Self {
+        Header::new(HeaderBar::new(left, HeaderContent::chat(ctx, profiles), right), None, None)
+    }
+
+    /// Starts with the title below the bar, it moves into the bar after the page content scrolls.
+    pub fn large(
+        ctx: &mut Context,
+        left: Option<IconButton>,
+        title: &'static str,
+        right: Option<IconButton>
     ) -> Self {
-        Header(
+        let mut content = HeaderContent::stack(ctx, title);
+        content.1.display(false);
+        Header::new(HeaderBar::new(left, content, right), Some(LargeTitle::new(ctx, title)), None)
+    }
+
+    /// A stack header with a search field below the bar, edits trigger `SearchChanged`.
+    pub fn search(
+        ctx: &mut Context,
+        left: Option<IconButton>,
+        title: &'static str,
+        placeholder: &'static str,
+        right: Option<IconButton>
+    ) -> Self {
+        let search = SearchBar::new(ctx, placeholder);
+        Header::new(HeaderBar::new(left, HeaderContent::stack(ctx, title), right), None, Some(search))
+    }
+
+    fn new(bar: HeaderBar, large: Option<LargeTitle>, search: Option<SearchBar>) -> Self {
+        Header(Column::new(0.0, Offset::Start, Size::Fit, Padding::default()), bar, large, search, None)
+    }
+
+    pub fn title(&mut self) -> String {self.1.2.title().clone()}
+
+    pub fn set_title(&mut self, title: impl Into<String>) {
+        let title = title.into();
+        if let Some(large) = &mut self.2 {*large.title() = title.clone();}
+        *self.1.2.title() = title;
+    }
+
+    /// Current text of the search field of `search` headers.
+    pub fn query(&mut self) -> Option<String> {self.3.as_mut().map(|search| search.query().clone())}
+
+    /// Collapses a large title based on the scroll offset of `scroll_view`, set by `Page::new`.
+    pub fn follow(&mut self, scroll_view: ElementID) {self.4 = Some(scroll_view);}
+}
+
+impl OnEvent for Header {
+    fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if let Some(ScrolledEvent(id, offset)) = event.downcast_ref::<ScrolledEvent>() {
+            if let (Some(large), true) = (&mut self.2, self.4 == Some(*id)) {
+                let collapsed = *offset > COLLAPSE;
+                large.1.display(!collapsed);
+                self.1.2.1.display(collapsed);
+            }
+        } else if event.downcast_ref::<ThemeChanged>().is_some() {
+            // The hidden one of the two titles gets no events
+            self.1.2.1.inner().color(ctx);
+            if let Some(large) = &mut self.2 {large.1.inner().color(ctx);}
+        }
+        true
+    }
+}
+
+#[derive(Debug, Component)]
+struct HeaderBar(Row, HeaderIcon, HeaderContent, HeaderIcon);
+impl OnEvent for HeaderBar {}
+
+impl HeaderBar {
+    fn new(left: Option<IconButton>, content: HeaderContent, right: Option<IconButton>) -> Self {
+        HeaderBar(
             Row(16.0, Offset::Center, Size::Fit, Padding(24.0, 16.0, 24.0, 16.0)),
-            HeaderIcon::new(left), 
-            HeaderContent::chat(ctx, avatars), 
+            HeaderIcon::new(left),
+            content,
             HeaderIcon::new(right)
         )
     }
 }
 
 #[derive(Debug, Component)]
-struct HeaderContent(Column, Text, Option<AvatarRow>);
+struct HeaderContent(Column, Opt<Text>, Option<AvatarRow>);
 impl OnEvent for HeaderContent {}
 
 impl HeaderContent {
@@ -190,7 +367,7 @@ impl HeaderContent {
         let width = Size::custom(move |widths: Vec<(f32, f32)>|(widths[0].0, f32::MAX));
         HeaderContent(
             Column::new(10.0, Offset::Center, width, Padding::default()), 
-            Text::new(ctx, title, TextStyle::Heading, text_size, Align::Left),
+            Opt::new(Text::new(ctx, title, TextStyle::Heading, text_size, Align::Left), true),
             None,
         )
     }
@@ -200,21 +377,75 @@ impl HeaderContent {
         let width = Size::custom(move |widths: Vec<(f32, f32)>|(widths[0].0, f32::MAX));
         HeaderContent(
             Column::new(10.0, Offset::Center, width, Padding::default()),  
-            Text::new(ctx, title, TextStyle::Heading, text_size, Align::Left),
+            Opt::new(Text::new(ctx, title, TextStyle::Heading, text_size, Align::Left), true),
             None,
         )
     }
 
-    pub fn chat(ctx: &mut Context, avatars: Vec<AvatarContent>) -> Self {
+    pub fn chat(ctx: &mut Context, profiles: Vec<Profile>) -> Self {
         let text_size = ctx.get::<PelicanUI>().theme.fonts.size.h5;
-        let title = if avatars.len() > 1 {"Ella Couch"} else {"Group Message"};
+        let names: Vec<&str> = profiles.iter().map(|profile| profile.name).collect();
+        let title = match names.as_slice() {
+            [] => String::new(),
+            [name] => name.to_string(),
+            [first, second] => format!("{} & {}", first, second),
+            [first, second, rest @ ..] => format!("{}, {} & {} more", first, second, rest.len()),
+        };
+        let avatars = profiles.into_iter().map(|profile| profile.avatar).collect();
         let width = Size::custom(move |widths: Vec<(f32, f32)>|(widths[0].0, f32::MAX));
+        let mut text = Text::new(ctx, "", TextStyle::Heading, text_size, Align::Left);
+        text.text().spans[0].text = title;
         HeaderContent(
             Column::new(10.0, Offset::Center, width, Padding::default()), 
-            Text::new(ctx, title, TextStyle::Heading, text_size, Align::Left),
+            Opt::new(text, true),
             Some(AvatarRow::new(ctx, avatars)),
         )
     }
+
+    fn title(&mut self) -> &mut String {&mut self.1.inner().text().spans[0].text}
+}
+
+#[derive(Debug, Component)]
+struct LargeTitle(Column, Opt<Text>);
+impl OnEvent for LargeTitle {}
+
+impl LargeTitle {
+    fn new(ctx: &mut Context, title: &'static str) -> Self {
+        let text_size = ctx.get::<PelicanUI>().theme.fonts.size.h2;
+        LargeTitle(
+            Column::new(0.0, Offset::Start, Size::fill(), Padding(24.0, 0.0, 24.0, 8.0)),
+            Opt::new(Text::new(ctx, title, TextStyle::Heading, text_size, Align::Left), true)
+        )
+    }
+
+    fn title(&mut self) -> &mut String {&mut self.1.inner().text().spans[0].text}
+}
+
+#[derive(Debug, Component)]
+struct SearchBar(Stack, TextInput, #[skip] String);
+
+impl SearchBar {
+    fn new(ctx: &mut Context, placeholder: &'static str) -> Self {
+        // The search button resends the current query, e.g. to retry a search
+        let search = |ctx: &mut Context, query: &mut String| ctx.trigger_event(SearchChanged(query.clone()));
+        let input = TextInput::new(ctx, None, None, placeholder, None, Some(("search", search)));
+        SearchBar(Stack(Offset::Center, Offset::Start, Size::fill(), Size::Fit, Padding(24.0, 0.0, 24.0, 8.0)), input, String::new())
+    }
+
+    fn query(&mut self) -> &mut String {self.1.get_value()}
+}
+
+impl OnEvent for SearchBar {
+    fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if let Some(TickEvent) = event.downcast_ref() {
+            let query = self.query().clone();
+            if query != self.2 {
+                self.2 = query.clone();
+                ctx.trigger_event(SearchChanged(query));
+            }
+        }
+        true
+    }
 }
 
 #[derive(Debug, Component)]
@@ -231,39 +462,219 @@ impl HeaderIcon {
 }
 
 
+/// Bottom bar of a page. Buttons are disabled while any `link`ed input reports an error.
 #[derive(Debug, Component)]
-pub struct Bumper (Stack, BumperContent);
-impl OnEvent for Bumper {}
+pub struct Bumper (Stack, BumperContent, #[skip] Vec<ElementID>, #[skip] HashSet<ElementID>, #[skip] Option<Vec<ButtonState>>);
+impl OnEvent for Bumper {
+    fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if let Some(LocateEvent(offset, _)) = event.downcast_ref::<LocateEvent>() {
+            ctx.get::<PelicanUI>().overlays.reserve(offset.1);
+        } else if let Some(InputValidity(id, valid)) = event.downcast_ref::<InputValidity>() {
+            if self.2.contains(id) {
+                if *valid {self.3.remove(id);} else {self.3.insert(*id);}
+                self.lock(ctx, !self.3.is_empty());
+            }
+        } else if event.downcast_ref::<ThemeChanged>().is_some() {
+            self.1.2.iter_mut().for_each(|button| button.color(ctx));
+        }
+        true
+    }
+}
 
 impl Bumper {
     pub fn new(content: Vec<Box<dyn Drawable>>) -> Self {
-        let width = Size::custom(move |widths: Vec<(f32, f32)>|(widths[0].0, 375.0));
-        Bumper(
-            Stack(Offset::Center, Offset::Start, width, Size::Fit, Padding(24.0, 16.0, 24.0, 16.0)),
-            BumperContent::new(content)
-        )
+        Bumper::with_buttons(content, vec![])
     }
 
     pub fn double_button(a: Button, b: Button) -> Self {
-        Self::new(vec![Box::new(a), Box::new(b)])
+        Bumper::with_buttons(vec![], vec![a, b])
     }
 
     pub fn single_button(a: Button) -> Self {
-        Self::new(vec![Box::new(a)])
+        Bumper::with_buttons(vec![], vec![a])
+    }
+
+    /// A summary line, e.g. a total, next to the page's primary button.
+    pub fn summary(ctx: &mut Context, summary: &'static str, button: Button) -> Self {
+        let font_size = ctx.get::<PelicanUI>().theme.fonts.size.md;
+        let summary = Bin (
+            Stack(Offset::Start, Offset::Center, Size::fill(), Size::Fit, Padding::default()),
+            Text::new(ctx, summary, TextStyle::Primary, font_size, Align::Left)
+        );
+        Bumper::with_buttons(vec![Box::new(summary)], vec![button])
+    }
+
+    /// Message composer with attachment and send buttons, `on_send` gets the typed text and the field is cleared.
+    pub fn chat(
+        ctx: &mut Context,
+        on_attach: impl FnMut(&mut Context) + 'static,
+        on_send: impl FnMut(&mut Context, String) + 'static,
+    ) -> Self {
+        Bumper::new(vec![Box::new(ChatComposer::new(ctx, on_attach, on_send))])
+    }
+
+    /// Disables the buttons while the input with `id` is in an error state.
+    pub fn link(mut self, id: ElementID) -> Self {
+        self.2.push(id);
+        self
     }
 
-    // pub fn message_input(a: TextInput) -> Self {
-    //     Self::new(vec![Box::new(a)])
-    // }
+    /// Content besides the buttons, e.g. the summary line or the chat composer.
     pub fn items(&mut self) -> &mut Vec<Box<dyn Drawable>> {&mut self.1.1}
+    /// Buttons of `single_button`, `double_button` and `summary` bumpers, drawn after the `items`.
+    pub fn buttons(&mut self) -> &mut Vec<Button> {&mut self.1.2}
+
+    fn with_buttons(content: Vec<Box<dyn Drawable>>, buttons: Vec<Button>) -> Self {
+        let width = Size::custom(move |widths: Vec<(f32, f32)>|(widths[0].0, 375.0));
+        Bumper(
+            Stack(Offset::Center, Offset::Start, width, Size::Fit, Padding(24.0, 16.0, 24.0, 16.0)),
+            BumperContent::new(content, buttons), vec![], HashSet::new(), None
+        )
+    }
+
+    // Disables every button, restoring the states they had once unlocked
+    fn lock(&mut self, ctx: &mut Context, locked: bool) {
+        match (locked, self.4.take()) {
+            (true, None) => {
+                self.4 = Some(self.1.2.iter_mut().map(|button| *button.status()).collect());
+                self.1.2.iter_mut().for_each(|button| {
+                    *button.status() = ButtonState::Disabled;
+                    button.color(ctx);
+                });
+            },
+            (false, Some(states)) => self.1.2.iter_mut().zip(states).for_each(|(button, state)| {
+                *button.status() = state;
+                button.color(ctx);
+            }),
+            (_, states) => self.4 = states
+        }
+    }
 }
 
 #[derive(Debug, Component)]
-pub struct BumperContent (Row, Vec<Box<dyn Drawable>>);
+pub struct BumperContent (Row, Vec<Box<dyn Drawable>>, Vec<Button>);
 impl OnEvent for BumperContent {}
 
 impl BumperContent {
-    pub fn new(content: Vec<Box<dyn Drawable>>) -> Self {
-        BumperContent(Row::center(16.0), content)
+    pub fn new(content: Vec<Box<dyn Drawable>>, buttons: Vec<Button>) -> Self {
+        BumperContent(Row::center(16.0), content, buttons)
+    }
+}
+
+#[derive(Component)]
+struct ChatComposer(
+    Row, IconButton, Bin<Stack, TextInput>, IconButton,
+    #[skip] Receiver<u8>, #[skip] Box<dyn FnMut(&mut Context, String)>
+);
+
+impl ChatComposer {
+    fn new(
+        ctx: &mut Context,
+        on_attach: impl FnMut(&mut Context) + 'static,
+        on_send: impl FnMut(&mut Context, String) + 'static,
+    ) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        let line_height = ctx.get::<PelicanUI>().theme.fonts.size.md * 1.25;
+        let height = Size::custom(move |heights: Vec<(f32, f32)>| (
+            ChatComposer::height(heights[0].0, line_height),
+            ChatComposer::height(heights[0].1, line_height)
+        ));
+        let input = Bin(
+            Stack(Offset::Start, Offset::End, Size::fill(), height, Padding::default()),
+            TextInput::new(ctx, None, None, "Message", None, None::<(&'static str, fn(&mut Context, &mut String))>)
+        );
+        ChatComposer(
+            // Buttons stay at the bottom as the field grows with each line
+            Row(8.0, Offset::End, Size::Fit, Padding::default()),
+            IconButton::input(ctx, "add", on_attach),
+            input,
+            IconButton::input(ctx, "send", move |_| {sender.send(0).unwrap();}),
+            receiver, Box::new(on_send)
+        )
+    }
+
+    /// Height of the field for the height of its text, it grows a line at a time as lines are typed up to `COMPOSER_LINES`.
+    fn height(content: f32, line_height: f32) -> f32 {
+        content.min(COMPOSER_PADDING + line_height * COMPOSER_LINES)
+    }
+}
+
+impl OnEvent for ChatComposer {
+    fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if let Some(TickEvent) = event.downcast_ref() {
+            if self.4.try_recv().is_ok() && !self.2.inner().get_value().trim().is_empty() {
+                let message = std::mem::take(self.2.inner().get_value());
+                (self.5)(ctx, message);
+            }
+        }
+        true
+    }
+}
+
+impl std::fmt::Debug for ChatComposer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ChatComposer(...)")
     }
-}
\ No newline at end of file
+}
+
+/// Marker on a navigation button, counts above 99 are shown as `99+`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Badge {
+    #[default]
+    None,
+    Dot,
+    Count(u32),
+}
+
+#[derive(Debug, Component)]
+pub struct BadgeView(Stack, Option<Shape>, Option<BadgeCount>);
+impl OnEvent for BadgeView {}
+
+impl BadgeView {
+    pub fn new(ctx: &mut Context, badge: Badge) -> Option<Self> {
+        let color = ctx.get::<PelicanUI>().theme.colors.status.danger;
+        let layout = Stack(Offset::End, Offset::Start, Size::fill(), Size::fill(), Padding::default());
+        match badge {
+            Badge::None | Badge::Count(0) => None,
+            Badge::Dot => Some(BadgeView(layout, Some(Circle::new(8.0, color)), None)),
+            Badge::Count(count) => Some(BadgeView(layout, None, Some(BadgeCount::new(ctx, count)))),
+        }
+    }
+}
+
+#[derive(Debug, Component)]
+pub struct BadgeCount(Stack, RoundedRectangle, Text);
+impl OnEvent for BadgeCount {}
+
+impl BadgeCount {
+    pub fn new(ctx: &mut Context, count: u32) -> Self {
+        let theme = &ctx.get::<PelicanUI>().theme;
+        let (color, font_size) = (theme.colors.status.danger, theme.fonts.size.xs);
+        let width = Size::custom(|widths: Vec<(f32, f32)>| {
+            let width = (widths[1].0 + 8.0).max(16.0);
+            (width, width)
+        });
+        let mut text = Text::new(ctx, "", TextStyle::White, font_size, Align::Center);
+        text.text().spans[0].text = if count > 99 {"99+".to_string()} else {count.to_string()};
+        BadgeCount(
+            Stack(Offset::Center, Offset::Center, width, Size::Static(16.0), Padding::default()),
+            RoundedRectangle::new(0.0, 8.0, color),
+            text
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn composer_grows_up_to_its_line_limit() {
+        let line = 20.0;
+        let lines = |count: f32| COMPOSER_PADDING + line * count;
+        assert_eq!(ChatComposer::height(lines(1.0), line), lines(1.0));
+        assert_eq!(ChatComposer::height(lines(3.0), line), lines(3.0));
+        assert_eq!(ChatComposer::height(lines(COMPOSER_LINES), line), lines(COMPOSER_LINES));
+        assert_eq!(ChatComposer::height(lines(9.0), line), lines(COMPOSER_LINES));
+    }
+}
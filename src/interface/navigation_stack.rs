@@ -0,0 +1,371 @@
+use rust_on_rails::prelude::*;
+use crate::elements::shapes::Rectangle;
+use crate::events::{NavigateEvent, ReplaceEvent, PopEvent, PopToRootEvent, TabSelected, OpenTabEvent, ThemeChanged};
+use crate::{PelicanUI, AppPage, AppFlow};
+
+use super::page_transition::{PageTransition, Transition, Direction};
+use super::session::{NavigationState, FlowState, FlowRegistry};
+
+use std::collections::HashMap;
+
+const EDGE: f32 = 20.0; // width of the screen edge a back swipe has to start in
+const SWIPE: f32 = 80.0; // distance a back swipe has to travel
+
+// What remapping tabs does to the visible tab
+#[derive(Debug, PartialEq, Eq)]
+enum Visible {
+    Moved(usize),
+    Restore(usize), // the visible tab was removed, show this retained one instead
+    Removed, // nothing retained to show, tab 0 has to be built from its root
+}
+
+impl Visible {
+    fn remap(tab: usize, retained: impl Iterator<Item = usize>, map: impl Fn(usize) -> Option<usize>) -> Self {
+        match map(tab) {
+            Some(tab) => Visible::Moved(tab),
+            None => retained.min().map(Visible::Restore).unwrap_or(Visible::Removed)
+        }
+    }
+}
+
+// A page with the transition it entered with and the flow that built it, if any
+#[derive(Debug)]
+struct Entry(Box<dyn AppPage>, Transition, Option<Box<dyn AppFlow>>);
+
+impl Entry {
+    fn new(ctx: &mut Context, flow: Box<dyn AppFlow>) -> Self {
+        Entry(flow.get_page(ctx), flow.transition(), Some(flow))
+    }
+}
+
+/// The visible page with the pages below it for the current tab, plus the full stacks of the other tabs.
+#[derive(Debug)]
+pub struct NavigationStack {
+    page: Entry,
+    tab: usize,
+    history: Vec<Entry>,
+    retained: HashMap<usize, Vec<Entry>>,
+    pending: Option<usize>,
+    swipe: Option<f32>,
+    leaving: Option<(PageTransition, Option<(Transition, Option<Box<dyn AppFlow>>)>)>,
+    veil: Rectangle,
+}
+
+impl NavigationStack {
+    pub fn new(ctx: &mut Context, tab: usize, page: Box<dyn AppPage>) -> Self {
+        let color = ctx.get::<PelicanUI>().theme.colors.background.primary;
+        let mut veil = Rectangle::new(color);
+        veil.shape().color.3 = 0;
+        NavigationStack{
+            page: Entry(page, Transition::None, None), tab, history: vec![], retained: HashMap::new(),
+            pending: None, swipe: None, leaving: None, veil
+        }
+    }
+
+    pub fn page(&mut self) -> &mut Box<dyn AppPage> {&mut self.page.0}
+    pub fn tab(&self) -> usize {self.tab}
+    pub fn has_nav(&self) -> bool {self.page.0.has_nav()}
+    pub fn is_switching(&self) -> bool {self.pending.is_some()}
+    pub fn is_animating(&self) -> bool {self.leaving.is_some()}
+
+    pub fn depth(&self) -> usize {
+        self.history.len() + self.leaving.as_ref().map(|(_, kept)| kept.is_some() as usize).unwrap_or_default()
+    }
+
+    pub fn can_pop(&self) -> bool {self.depth() > 0}
+
+    pub fn push(&mut self, ctx: &mut Context, flow: Box<dyn AppFlow>) {
+        let entry = Entry::new(ctx, flow);
+        self.enter(ctx, entry);
+    }
+
+    /// Pushes a page that was not built from a flow, it is left out of snapshots.
+    pub fn push_page(&mut self, ctx: &mut Context, page: Box<dyn AppPage>, transition: Transition) {
+        self.enter(ctx, Entry(page, transition, None));
+    }
+
+    pub fn replace(&mut self, ctx: &mut Context, flow: Box<dyn AppFlow>) {
+        self.settle();
+        let outgoing = std::mem::replace(&mut self.page, Entry::new(ctx, flow));
+        self.animate(ctx, self.page.1, Direction::Push, outgoing, false);
+    }
+
+    pub fn pop(&mut self, ctx: &mut Context) -> bool {
+        self.settle();
+        match self.history.pop() {
+            Some(previous) => {
+                let outgoing = std::mem::replace(&mut self.page, previous);
+                self.animate(ctx, outgoing.1, Direction::Pop, outgoing, false);
+                true
+            },
+            None => false
+        }
+    }
+
+    pub fn pop_to_root(&mut self, ctx: &mut Context) -> bool {
+        self.settle();
+        match self.history.is_empty() {
+            true => false,
+            false => {
+                let root = self.history.remove(0);
+                self.history.clear();
+                let outgoing = std::mem::replace(&mut self.page, root);
+                self.animate(ctx, outgoing.1, Direction::Pop, outgoing, false);
+                true
+            }
+        }
+    }
+
+    /// Stores the visible tab and shows `tab`, restoring its stack when it was visited before.
+    pub fn switch(&mut self, ctx: &mut Context, tab: usize, flow: Box<dyn AppFlow>) {
+        self.settle();
+        ctx.get::<PelicanUI>().overlays.relocate();
+        if tab == self.tab {
+            self.history.clear();
+            self.page = Entry::new(ctx, flow);
+            self.page.1 = Transition::None;
+            return;
+        }
+        let mut restored = self.retained.remove(&tab).unwrap_or_default();
+        let next = restored.pop().unwrap_or_else(|| Entry(flow.get_page(ctx), Transition::None, Some(flow)));
+        let mut stack = std::mem::replace(&mut self.history, restored);
+        stack.push(std::mem::replace(&mut self.page, next));
+        self.retained.insert(self.tab, stack);
+        self.tab = tab;
+    }
+
+    /// Shows `flow` on top of `tab`, used for deep links. A tab without history gets `flow` as its root.
+    pub fn open(&mut self, ctx: &mut Context, tab: usize, flow: Box<dyn AppFlow>) {
+        self.settle();
+        self.pending = None;
+        if tab == self.tab {return self.push(ctx, flow);}
+        let mut stack = std::mem::take(&mut self.history);
+        match self.retained.remove(&tab) {
+            Some(mut restored) => {
+                let top = restored.pop().expect("retained stacks hold their visible page");
+                stack.push(std::mem::replace(&mut self.page, top));
+                self.history = restored;
+                self.retained.insert(self.tab, stack);
+                self.tab = tab;
+                self.push(ctx, flow);
+            },
+            None => {
+                stack.push(std::mem::replace(&mut self.page, Entry(flow.get_page(ctx), Transition::None, Some(flow))));
+                self.retained.insert(self.tab, stack);
+                self.tab = tab;
+            }
+        }
+    }
+
+    /// Moves every stack to its tab's new index after tabs were added, removed or reordered.
+    /// Stacks of removed tabs are dropped, the lowest remaining tab is shown when the visible one goes.
+    /// Returns false when no other stack was left to show, the caller then has to navigate to tab 0's root.
+    pub fn remap(&mut self, map: impl Fn(usize) -> Option<usize>) -> bool {
+        self.settle();
+        self.pending = self.pending.and_then(&map);
+        self.retained = std::mem::take(&mut self.retained).into_iter()
+            .filter_map(|(tab, stack)| map(tab).map(|tab| (tab, stack)))
+            .collect();
+        match Visible::remap(self.tab, self.retained.keys().copied(), map) {
+            Visible::Moved(tab) => self.tab = tab,
+            Visible::Restore(tab) => {
+                let mut stack = self.retained.remove(&tab).unwrap_or_default();
+                self.page = stack.pop().expect("retained stacks hold their visible page");
+                self.history = stack;
+                self.tab = tab;
+            },
+            Visible::Removed => {
+                // The page stays until the root of tab 0 replaces it
+                self.history.clear();
+                self.tab = 0;
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Saves the flows of every tab, pages from flows without a name are skipped.
+    pub fn snapshot(&self) -> NavigationState {
+        let flows = |stack: &[Entry]| stack.iter().filter_map(|e| e.2.as_deref().and_then(FlowState::of)).collect::<Vec<_>>();
+        let mut current = flows(&self.history);
+        if let Some((_, Some((_, Some(flow))))) = &self.leaving {current.extend(FlowState::of(flow.as_ref()));}
+        current.extend(flows(std::slice::from_ref(&self.page)));
+        let mut stacks: Vec<(usize, Vec<FlowState>)> = self.retained.iter().map(|(tab, stack)| (*tab, flows(stack))).collect();
+        stacks.push((self.tab, current));
+        stacks.sort_by_key(|(tab, _)| *tab);
+        NavigationState{tab: self.tab, stacks}
+    }
+
+    /// Rebuilds the saved stacks on top of this one, meant to be used once at launch.
+    /// Pages already in the current tab stay below its restored pages.
+    pub fn restore(mut self, ctx: &mut Context, state: &NavigationState, registry: &FlowRegistry) -> Self {
+        self.settle();
+        let NavigationStack{page, tab, mut history, retained, veil, ..} = self;
+        history.push(page);
+        let mut stacks = retained;
+        stacks.insert(tab, history);
+        for (saved, flows) in &state.stacks {
+            let restored: Vec<Entry> = flows.iter().filter_map(|f| registry.restore(f)).map(|flow| Entry::new(ctx, flow)).collect();
+            let stack = stacks.entry(*saved).or_default();
+            if *saved != tab {stack.clear();}
+            stack.extend(restored);
+        }
+        let active = if stacks.get(&state.tab).is_some_and(|s| !s.is_empty()) {state.tab} else {tab};
+        let mut history = stacks.remove(&active).unwrap_or_default();
+        let page = history.pop().expect("the active tab holds at least one page");
+        NavigationStack{
+            page, tab: active, history, retained: stacks.into_iter().filter(|(_, s)| !s.is_empty()).collect(),
+            pending: None, swipe: None, leaving: None, veil
+        }
+    }
+
+    fn enter(&mut self, ctx: &mut Context, entry: Entry) {
+        self.settle();
+        let outgoing = std::mem::replace(&mut self.page, entry);
+        self.animate(ctx, self.page.1, Direction::Push, outgoing, true);
+    }
+
+    fn animate(&mut self, ctx: &mut Context, transition: Transition, direction: Direction, outgoing: Entry, keep: bool) {
+        let Entry(page, kept, flow) = outgoing;
+        // The veil is drawn from the first frame, before the tick that sets its opacity
+        self.veil.shape().color.3 = 0;
+        self.leaving = Some((PageTransition::new(transition, direction, page), keep.then_some((kept, flow))));
+        ctx.get::<PelicanUI>().overlays.relocate();
+        if !ctx.get::<PelicanUI>().animate() || transition == Transition::None {self.settle();}
+    }
+
+    fn settle(&mut self) {
+        if let Some((leaving, Some((transition, flow)))) = self.leaving.take() {
+            self.history.push(Entry(leaving.outgoing, transition, flow));
+        }
+    }
+}
+
+impl OnEvent for NavigationStack {
+    fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if let Some(TickEvent) = event.downcast_ref() {
+            // Anchors and the bottom chrome move with the pages
+            if self.leaving.is_some() {ctx.get::<PelicanUI>().overlays.relocate();}
+            if self.leaving.as_ref().is_some_and(|(leaving, _)| leaving.is_finished()) {self.settle();}
+            if let Some((leaving, _)) = &self.leaving {
+                if leaving.transition == Transition::Fade {self.veil.shape().color.3 = leaving.veil().1;}
+            }
+            // A tab callback navigates in the same frame, a selection that did not is not a pending switch
+            self.pending = None;
+        } else if event.downcast_ref::<ThemeChanged>().is_some() {
+            let alpha = self.veil.shape().color.3;
+            self.veil.shape().color = ctx.get::<PelicanUI>().theme.colors.background.primary;
+            self.veil.shape().color.3 = alpha;
+        } else if let Some(TabSelected(tab)) = event.downcast_ref::<TabSelected>() {
+            self.pending = Some(*tab);
+        } else if let Some(navigate) = event.downcast_ref::<NavigateEvent>() {
+            match self.pending.take() {
+                Some(tab) => self.switch(ctx, tab, navigate.0.clone()),
+                None => self.push(ctx, navigate.0.clone())
+            }
+        } else if let Some(OpenTabEvent(tab, flow)) = event.downcast_ref::<OpenTabEvent>() {
+            self.open(ctx, *tab, flow.clone());
+        } else if let Some(ReplaceEvent(flow)) = event.downcast_ref::<ReplaceEvent>() {
+            self.replace(ctx, flow.clone());
+        } else if event.downcast_ref::<PopEvent>().is_some() {
+            self.pop(ctx);
+        } else if event.downcast_ref::<PopToRootEvent>().is_some() {
+            self.pop_to_root(ctx);
+        } else if let Some(KeyboardEvent{state: KeyboardState::Pressed, key: Key::Named(NamedKey::Escape)}) = event.downcast_ref() {
+            if !crate::config::IS_MOBILE && self.can_pop() {ctx.trigger_event(PopEvent);}
+        } else if let Some(event) = event.downcast_ref::<MouseEvent>() {
+            match event {
+                MouseEvent{state: MouseState::Pressed, position: Some(p)} if crate::config::IS_MOBILE && p.0 < EDGE && self.can_pop() => self.swipe = Some(p.0),
+                MouseEvent{state: MouseState::Moved, position: Some(p)} => if let Some(start) = self.swipe {
+                    if p.0 - start > SWIPE {
+                        self.swipe = None;
+                        ctx.trigger_event(PopEvent);
+                    }
+                },
+                MouseEvent{state: MouseState::Released, ..} => self.swipe = None,
+                _ => {}
+            }
+        }
+        true
+    }
+}
+
+impl Component for NavigationStack {
+    fn children_mut(&mut self) -> Vec<&mut dyn Drawable> {
+        let page = self.page.0.as_mut();
+        match &mut self.leaving {
+            None => vec![page],
+            Some((leaving, _)) if leaving.transition == Transition::Fade => match leaving.veil().0 {
+                true => vec![page, &mut self.veil],
+                false => vec![leaving.outgoing.as_mut(), &mut self.veil],
+            },
+            Some((leaving, _)) if leaving.incoming_on_top() => vec![leaving.outgoing.as_mut(), page],
+            Some((leaving, _)) => vec![page, leaving.outgoing.as_mut()],
+        }
+    }
+
+    fn children(&self) -> Vec<&dyn Drawable> {
+        let page = self.page.0.as_ref();
+        match &self.leaving {
+            None => vec![page],
+            Some((leaving, _)) if leaving.transition == Transition::Fade => match leaving.veil().0 {
+                true => vec![page, &self.veil],
+                false => vec![leaving.outgoing.as_ref(), &self.veil],
+            },
+            Some((leaving, _)) if leaving.incoming_on_top() => vec![leaving.outgoing.as_ref(), page],
+            Some((leaving, _)) => vec![page, leaving.outgoing.as_ref()],
+        }
+    }
+
+    fn request_size(&self, _ctx: &mut Context, children: Vec<SizeRequest>) -> SizeRequest {
+        // Only the settled page decides the size, animating pages are laid out in the same space
+        let page = match &self.leaving {
+            Some((leaving, _)) if leaving.incoming_on_top() && leaving.transition != Transition::Fade => &children[1],
+            _ => &children[0],
+        };
+        SizeRequest::new(page.min_width(), page.min_height(), f32::MAX, f32::MAX)
+    }
+
+    fn build(&mut self, _ctx: &mut Context, size: (f32, f32), children: Vec<SizeRequest>) -> Vec<Area> {
+        let (incoming, outgoing) = match &self.leaving {
+            Some((leaving, _)) => leaving.offsets(size),
+            None => ((0.0, 0.0), (0.0, 0.0))
+        };
+        let offsets = match &self.leaving {
+            None => vec![incoming],
+            Some((leaving, _)) if leaving.transition == Transition::Fade => vec![(0.0, 0.0), (0.0, 0.0)],
+            Some((leaving, _)) if leaving.incoming_on_top() => vec![outgoing, incoming],
+            Some(_) => vec![incoming, outgoing],
+        };
+        offsets.into_iter().zip(children).map(|(offset, child)| Area{offset, size: child.get(size)}).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn removing(index: usize) -> impl Fn(usize) -> Option<usize> {
+        move |tab| match tab.cmp(&index) {
+            std::cmp::Ordering::Less => Some(tab),
+            std::cmp::Ordering::Equal => None,
+            std::cmp::Ordering::Greater => Some(tab - 1),
+        }
+    }
+
+    #[test]
+    fn visible_tab_follows_its_new_index() {
+        assert_eq!(Visible::remap(2, [0].into_iter(), removing(1)), Visible::Moved(1));
+        assert_eq!(Visible::remap(0, [].into_iter(), |tab| Some(tab + 1)), Visible::Moved(1));
+    }
+
+    #[test]
+    fn removed_visible_tab_shows_lowest_retained() {
+        assert_eq!(Visible::remap(1, [2, 0].into_iter(), removing(1)), Visible::Restore(0));
+    }
+
+    #[test]
+    fn removing_the_only_visible_tab_needs_a_root() {
+        assert_eq!(Visible::remap(0, [].into_iter(), removing(0)), Visible::Removed);
+    }
+}
@@ -0,0 +1,214 @@
+use rust_on_rails::prelude::*;
+use crate::events::{LocateEvent, OverlayDismissed, ScrolledEvent, SafeAreaChanged, KeyboardActiveEvent};
+use crate::layout::{Offset, Stack};
+use crate::{PelicanUI, ElementID};
+
+use std::collections::HashMap;
+
+const GAP: f32 = 8.0; // space between an anchor and its overlay
+const EDGE: f32 = 8.0; // closest an anchored overlay gets to the screen edge
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Placement {
+    Above,
+    Below,
+    Left,
+    Right,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum OverlayAnchor {
+    Element(ElementID, Placement),
+    Screen(Offset, Offset),
+}
+
+#[derive(Debug)]
+pub struct OverlayItem {
+    pub id: ElementID,
+    pub anchor: OverlayAnchor,
+    pub z: i32,
+    pub dismissible: bool,
+    pub modal: bool,
+    content: Box<dyn Drawable>,
+}
+
+impl OverlayItem {
+    pub fn new(content: impl Drawable + 'static, anchor: OverlayAnchor) -> Self {
+        OverlayItem{id: ElementID::new(), anchor, z: 0, dismissible: true, modal: false, content: Box::new(content)}
+    }
+
+    pub fn content(&mut self) -> &mut Box<dyn Drawable> {&mut self.content}
+}
+
+/// Floating content waiting to be picked up by the interface, plus the last known area of every `Anchor`.
+#[derive(Debug, Default)]
+pub struct Overlays {
+    pending: Vec<OverlayItem>,
+    dismissed: Vec<ElementID>,
+    anchors: HashMap<ElementID, ((f32, f32), (f32, f32))>,
+    modal: bool,
+    chrome: Option<f32>,
+    measuring: Option<Option<f32>>,
+    relocate: bool,
+}
+
+impl Overlays {
+    pub fn show(&mut self, item: OverlayItem) -> ElementID {
+        let id = item.id;
+        self.pending.push(item);
+        self.relocate = true;
+        id
+    }
+
+    /// Asks for anchors and the bottom chrome to be measured again on the next tick, after a layout change.
+    pub fn relocate(&mut self) {self.relocate = true;}
+
+    pub fn dismiss(&mut self, id: ElementID) {
+        self.pending.retain(|item| item.id != id);
+        self.dismissed.push(id);
+    }
+
+    /// Top edge of the navigator and bumper at the bottom of the screen, from the last `LocateEvent`.
+    pub fn chrome(&self) -> Option<f32> {self.chrome}
+
+    pub(crate) fn reserve(&mut self, top: f32) {
+        if let Some(measured) = &mut self.measuring {
+            *measured = Some(measured.map_or(top, |chrome| chrome.min(top)));
+        }
+    }
+
+    /// Whether a shown overlay blocks input to the pages below it.
+    pub fn is_modal(&self) -> bool {self.modal}
+
+    pub fn anchor(&self, id: ElementID) -> Option<((f32, f32), (f32, f32))> {
+        self.anchors.get(&id).copied()
+    }
+}
+
+/// Marks its content as a target overlays can be anchored to.
+#[derive(Debug, Component)]
+pub struct Anchor<D: Drawable + 'static>(Stack, D, #[skip] ElementID);
+
+impl<D: Drawable + 'static> Anchor<D> {
+    pub fn new(id: ElementID, content: D) -> Self {
+        Anchor(Stack::default(), content, id)
+    }
+
+    pub fn id(&self) -> ElementID {self.2}
+    pub fn inner(&mut self) -> &mut D {&mut self.1}
+}
+
+impl<D: Drawable + 'static> OnEvent for Anchor<D> {
+    fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if let Some(LocateEvent(offset, size)) = event.downcast_ref::<LocateEvent>() {
+            ctx.get::<PelicanUI>().overlays.anchors.insert(self.2, (*offset, *size));
+        }
+        true
+    }
+}
+
+/// Top layer of the `Interface`, draws overlays above every page, navigator and keyboard.
+#[derive(Debug, Default)]
+pub struct OverlayLayer(Vec<OverlayItem>, Vec<((f32, f32), (f32, f32))>);
+// items sorted by z, areas from the last build
+
+impl OverlayLayer {
+    pub fn new() -> Self {OverlayLayer::default()}
+
+    pub fn is_empty(&self) -> bool {self.0.is_empty()}
+
+    /// Dismisses the top overlay when `position` is outside of it, returns whether the press was used for that.
+    pub(crate) fn dismiss_outside(&mut self, ctx: &mut Context, position: (f32, f32)) -> bool {
+        let top = self.0.len().checked_sub(1).filter(|_| self.1.len() == self.0.len());
+        if let (Some(index), Some((offset, size))) = (top, self.1.last().copied()) {
+            let inside = position.0 >= offset.0 && position.0 <= offset.0 + size.0
+                && position.1 >= offset.1 && position.1 <= offset.1 + size.1;
+            if !inside && self.0[index].dismissible {
+                self.dismiss(ctx, index);
+                return true;
+            }
+        }
+        false
+    }
+
+    fn dismiss(&mut self, ctx: &mut Context, index: usize) {
+        let item = self.0.remove(index);
+        self.1.clear();
+        ctx.trigger_event(OverlayDismissed(item.id));
+    }
+
+    fn position(ctx: &mut Context, anchor: OverlayAnchor, screen: (f32, f32), size: (f32, f32)) -> Option<(f32, f32)> {
+        let (id, placement) = match anchor {
+            OverlayAnchor::Screen(x, y) => return Some((x.get(screen.0, size.0), y.get(screen.1, size.1))),
+            OverlayAnchor::Element(id, placement) => (id, placement)
+        };
+        let (a_offset, a_size) = ctx.get::<PelicanUI>().overlays.anchor(id)?;
+
+        let center = (a_offset.0 + (a_size.0 - size.0) / 2.0, a_offset.1 + (a_size.1 - size.1) / 2.0);
+        let above = a_offset.1 - size.1 - GAP;
+        let below = a_offset.1 + a_size.1 + GAP;
+        let left = a_offset.0 - size.0 - GAP;
+        let right = a_offset.0 + a_size.0 + GAP;
+
+        // Flip to the opposite side when the preferred one runs off the screen
+        let (x, y) = match placement {
+            Placement::Below if below + size.1 > screen.1 && above >= 0.0 => (center.0, above),
+            Placement::Below => (center.0, below),
+            Placement::Above if above < 0.0 && below + size.1 <= screen.1 => (center.0, below),
+            Placement::Above => (center.0, above),
+            Placement::Right if right + size.0 > screen.0 && left >= 0.0 => (left, center.1),
+            Placement::Right => (right, center.1),
+            Placement::Left if left < 0.0 && right + size.0 <= screen.0 => (right, center.1),
+            Placement::Left => (left, center.1),
+        };
+
+        let clamp = |v: f32, item: f32, max: f32| if item + EDGE*2.0 > max {(max - item) / 2.0} else {v.clamp(EDGE, max - item - EDGE)};
+        Some((clamp(x, size.0, screen.0), clamp(y, size.1, screen.1)))
+    }
+}
+
+impl OnEvent for OverlayLayer {
+    fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if let Some(TickEvent) = event.downcast_ref() {
+            let overlays = &mut ctx.get::<PelicanUI>().overlays;
+            let dismissed = std::mem::take(&mut overlays.dismissed);
+            let pending = std::mem::take(&mut overlays.pending);
+            self.0.retain(|item| !dismissed.contains(&item.id));
+            self.0.extend(pending);
+            self.0.sort_by_key(|item| item.z);
+            overlays.modal = self.0.iter().any(|item| item.modal);
+
+            // The last measurement is complete once a tick passed since its LocateEvent
+            if let Some(measured) = overlays.measuring.take() {overlays.chrome = measured;}
+            if std::mem::take(&mut overlays.relocate) {
+                overlays.measuring = Some(None);
+                ctx.trigger_event(LocateEvent::default());
+            }
+        } else if event.downcast_ref::<ScrolledEvent>().is_some()
+            || event.downcast_ref::<SafeAreaChanged>().is_some()
+            || event.downcast_ref::<KeyboardActiveEvent>().is_some() {
+            ctx.get::<PelicanUI>().overlays.relocate();
+        }
+        true
+    }
+}
+
+impl Component for OverlayLayer {
+    fn children_mut(&mut self) -> Vec<&mut dyn Drawable> {self.0.iter_mut().map(|i| i.content.as_mut()).collect()}
+    fn children(&self) -> Vec<&dyn Drawable> {self.0.iter().map(|i| i.content.as_ref()).collect()}
+
+    fn request_size(&self, _ctx: &mut Context, _children: Vec<SizeRequest>) -> SizeRequest {
+        SizeRequest::fill()
+    }
+
+    fn build(&mut self, ctx: &mut Context, size: (f32, f32), children: Vec<SizeRequest>) -> Vec<Area> {
+        self.1 = self.0.iter().zip(children).map(|(item, i)| {
+            let item_size = i.get(size);
+            match OverlayLayer::position(ctx, item.anchor, size, item_size) {
+                Some(offset) => (offset, item_size),
+                None => ((0.0, 0.0), (0.0, 0.0)) // Anchor not located yet
+            }
+        }).collect();
+        self.1.iter().map(|(offset, size)| Area{offset: *offset, size: *size}).collect()
+    }
+}
@@ -0,0 +1,69 @@
+use rust_on_rails::prelude::*;
+use crate::AppPage;
+
+use std::time::Instant;
+
+const DURATION: f32 = 0.3; // seconds
+const PARALLAX: f32 = 0.3; // share of the width the page underneath moves during a slide
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Transition {
+    #[default]
+    Slide,
+    Fade,
+    ModalRise,
+    None,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    Push,
+    Pop,
+}
+
+/// An outgoing page animating away from the incoming one.
+#[derive(Debug)]
+pub struct PageTransition {
+    pub transition: Transition,
+    pub direction: Direction,
+    pub outgoing: Box<dyn AppPage>,
+    start: Instant,
+}
+
+impl PageTransition {
+    pub fn new(transition: Transition, direction: Direction, outgoing: Box<dyn AppPage>) -> Self {
+        PageTransition{transition, direction, outgoing, start: Instant::now()}
+    }
+
+    /// Eased progress from 0 to 1.
+    pub fn progress(&self) -> f32 {
+        let t = (self.start.elapsed().as_secs_f32() / DURATION).min(1.0);
+        1.0 - (1.0 - t).powi(3)
+    }
+
+    pub fn is_finished(&self) -> bool {self.start.elapsed().as_secs_f32() >= DURATION}
+
+    /// The incoming page is drawn above the outgoing one when pushing.
+    pub fn incoming_on_top(&self) -> bool {self.direction == Direction::Push}
+
+    /// Offsets of the incoming and outgoing page inside `size`.
+    pub fn offsets(&self, size: (f32, f32)) -> ((f32, f32), (f32, f32)) {
+        let p = self.progress();
+        match (self.transition, self.direction) {
+            (Transition::Slide, Direction::Push) => ((size.0*(1.0-p), 0.0), (-size.0*PARALLAX*p, 0.0)),
+            (Transition::Slide, Direction::Pop) => ((-size.0*PARALLAX*(1.0-p), 0.0), (size.0*p, 0.0)),
+            (Transition::ModalRise, Direction::Push) => ((0.0, size.1*(1.0-p)), (0.0, 0.0)),
+            (Transition::ModalRise, Direction::Pop) => ((0.0, 0.0), (0.0, size.1*p)),
+            _ => ((0.0, 0.0), (0.0, 0.0))
+        }
+    }
+
+    /// Fades pass through the background, returns whether the incoming page is showing yet and the veil opacity.
+    pub fn veil(&self) -> (bool, u8) {
+        let p = self.progress();
+        match p < 0.5 {
+            true => (false, (p * 2.0 * 255.0) as u8),
+            false => (true, ((1.0 - p) * 2.0 * 255.0) as u8),
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use crate::AppFlow;
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+
+type FlowBuilder = Box<dyn Fn(&Link) -> Option<Box<dyn AppFlow>>>;
+
+/// A parsed URL such as `app://wallet/send?amount=10` or `bitcoin:bc1q...?amount=0.1`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Link {
+    pub scheme: Option<String>,
+    pub path: Vec<String>,
+    pub query: HashMap<String, String>,
+    pub params: HashMap<String, String>,
+}
+
+impl Link {
+    pub fn parse(url: &str) -> Self {
+        let url = url.trim();
+        let (scheme, rest) = match url.split_once(':') {
+            Some((scheme, rest)) if !scheme.is_empty() && scheme.chars().all(|c| c.is_ascii_alphanumeric() || "+-.".contains(c)) => {
+                (Some(scheme.to_lowercase()), rest.trim_start_matches("//"))
+            },
+            _ => (None, url)
+        };
+        let (path, query) = rest.split_once('?').unwrap_or((rest, ""));
+        Link {
+            scheme,
+            path: path.split('/').filter(|s| !s.is_empty()).map(decode).collect(),
+            query: query.split('&').filter(|s| !s.is_empty()).map(|pair| {
+                let (k, v) = pair.split_once('=').unwrap_or((pair, ""));
+                (decode(k), decode(v))
+            }).collect(),
+            params: HashMap::new(),
+        }
+    }
+
+    /// Looks up a path parameter first, then a query value.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.params.get(key).or(self.query.get(key)).map(|v| v.as_str())
+    }
+}
+
+struct Route {
+    pattern: Link,
+    tab: usize,
+    builder: FlowBuilder,
+}
+
+impl Route {
+    // Segments starting with ':' capture the matching path segment
+    fn matches(&self, link: &Link) -> Option<Link> {
+        if self.pattern.scheme.is_some() && self.pattern.scheme != link.scheme {return None;}
+        if self.pattern.path.len() != link.path.len() {return None;}
+        let mut link = link.clone();
+        for (pattern, segment) in self.pattern.path.iter().zip(&link.path) {
+            match pattern.strip_prefix(':') {
+                Some(name) => {link.params.insert(name.to_string(), segment.clone());},
+                None if pattern == segment => {},
+                None => return None
+            }
+        }
+        Some(link)
+    }
+}
+
+/// Maps URL patterns to the tab and `AppFlow` they open, the first matching route wins.
+#[derive(Default)]
+pub struct Router(Vec<Route>);
+
+impl Router {
+    pub fn new() -> Self {Router::default()}
+
+    /// Registers `pattern`, e.g. `app://wallet/send`, `app://messages/:id` or `bitcoin::address`.
+    pub fn route(
+        mut self, pattern: &str, tab: usize,
+        builder: impl Fn(&Link) -> Option<Box<dyn AppFlow>> + 'static
+    ) -> Self {
+        self.0.push(Route{pattern: Link::parse(pattern), tab, builder: Box::new(builder)});
+        self
+    }
+
+    pub fn resolve(&self, url: &str) -> Option<(usize, Box<dyn AppFlow>)> {
+        let link = Link::parse(url);
+        self.0.iter().find_map(|route| {
+            route.matches(&link).and_then(|link| (route.builder)(&link)).map(|flow| (route.tab, flow))
+        })
+    }
+}
+
+impl Debug for Router {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.0.iter().map(|route| (&route.pattern.scheme, &route.pattern.path, route.tab))).finish()
+    }
+}
+
+// Percent decoding, '+' is treated as a space as in form encoded queries
+fn decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes.get(i+1..i+3).and_then(|h| std::str::from_utf8(h).ok()).and_then(|h| u8::from_str_radix(h, 16).ok());
+        match (bytes[i], hex) {
+            (b'%', Some(byte)) => {out.push(byte); i += 3;},
+            (b'+', _) => {out.push(b' '); i += 1;},
+            (b, _) => {out.push(b); i += 1;}
+        }
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_percent_and_plus() {
+        assert_eq!(decode("a%20b+c"), "a b c");
+        assert_eq!(decode("%E2%82%BF"), "\u{20bf}");
+        assert_eq!(decode("100%"), "100%");
+        assert_eq!(decode("%zz"), "%zz");
+    }
+
+    #[test]
+    fn parse_url_with_authority_and_query() {
+        let link = Link::parse("App://wallet/send?amount=10&note=hi%21");
+        assert_eq!(link.scheme.as_deref(), Some("app"));
+        assert_eq!(link.path, vec!["wallet", "send"]);
+        assert_eq!(link.get("amount"), Some("10"));
+        assert_eq!(link.get("note"), Some("hi!"));
+    }
+
+    #[test]
+    fn parse_uri_without_authority() {
+        let link = Link::parse("bitcoin:bc1qexample?amount=0.1");
+        assert_eq!(link.scheme.as_deref(), Some("bitcoin"));
+        assert_eq!(link.path, vec!["bc1qexample"]);
+        assert_eq!(link.get("amount"), Some("0.1"));
+    }
+
+    #[test]
+    fn route_captures_parameters() {
+        let route = Route{pattern: Link::parse("app://messages/:id"), tab: 1, builder: Box::new(|_| None)};
+        let link = route.matches(&Link::parse("app://messages/42?draft=1")).expect("route matches");
+        assert_eq!(link.get("id"), Some("42"));
+        assert_eq!(link.get("draft"), Some("1"));
+        assert!(route.matches(&Link::parse("app://messages")).is_none());
+        assert!(route.matches(&Link::parse("other://messages/42")).is_none());
+    }
+}
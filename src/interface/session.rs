@@ -0,0 +1,112 @@
+use serde::{Serialize, Deserialize};
+use crate::AppFlow;
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::path::Path;
+
+type FlowRestorer = Box<dyn Fn(&serde_json::Value) -> Option<Box<dyn AppFlow>>>;
+
+/// A flow saved by its registered name and payload.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FlowState {
+    pub name: String,
+    pub payload: serde_json::Value,
+}
+
+impl FlowState {
+    pub fn of(flow: &dyn AppFlow) -> Option<Self> {
+        flow.name().map(|name| FlowState{name: name.to_string(), payload: flow.payload()})
+    }
+}
+
+/// The selected tab and the flows of every tab's back stack, bottom to top.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct NavigationState {
+    pub tab: usize,
+    pub stacks: Vec<(usize, Vec<FlowState>)>,
+}
+
+impl NavigationState {
+    pub fn save(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        let json = serde_json::to_string(self).map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+        std::fs::write(path, json)
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let json = std::fs::read_to_string(path)?;
+        serde_json::from_str(&json).map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+    }
+
+    /// Drops the stacks of tabs at or past `tabs`, e.g. from a session saved with more tabs.
+    /// A selected tab that no longer exists falls back to tab 0.
+    pub fn within(&self, tabs: usize) -> Self {
+        NavigationState{
+            tab: if self.tab < tabs {self.tab} else {0},
+            stacks: self.stacks.iter().filter(|(tab, _)| *tab < tabs).cloned().collect(),
+        }
+    }
+}
+
+/// Rebuilds flows from the names they were saved under.
+#[derive(Default)]
+pub struct FlowRegistry(HashMap<&'static str, FlowRestorer>);
+
+impl FlowRegistry {
+    pub fn new() -> Self {FlowRegistry::default()}
+
+    pub fn register(
+        mut self, name: &'static str,
+        restore: impl Fn(&serde_json::Value) -> Option<Box<dyn AppFlow>> + 'static
+    ) -> Self {
+        self.0.insert(name, Box::new(restore));
+        self
+    }
+
+    /// Unknown names and payloads the flow rejects give `None`.
+    pub fn restore(&self, state: &FlowState) -> Option<Box<dyn AppFlow>> {
+        self.0.get(state.name.as_str()).and_then(|restore| restore(&state.payload))
+    }
+}
+
+impl Debug for FlowRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.0.keys()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state() -> NavigationState {
+        let flow = |name: &str| FlowState{name: name.to_string(), payload: serde_json::json!({"id": 7})};
+        NavigationState{tab: 2, stacks: vec![(0, vec![flow("home")]), (2, vec![flow("send"), flow("review")])]}
+    }
+
+    #[test]
+    fn save_and_load_round_trip() {
+        let path = std::env::temp_dir().join(format!("pelican-session-{}.json", std::process::id()));
+        state().save(&path).unwrap();
+        let loaded = NavigationState::load(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(loaded.unwrap(), state());
+    }
+
+    #[test]
+    fn load_rejects_malformed_json() {
+        let path = std::env::temp_dir().join(format!("pelican-session-bad-{}.json", std::process::id()));
+        std::fs::write(&path, "{\"tab\": \"two\"}").unwrap();
+        let error = NavigationState::load(&path).unwrap_err();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn within_drops_missing_tabs() {
+        let state = state().within(2);
+        assert_eq!(state.tab, 0);
+        assert_eq!(state.stacks.iter().map(|(tab, _)| *tab).collect::<Vec<_>>(), vec![0]);
+        assert_eq!(state.within(3), state);
+    }
+}
@@ -0,0 +1,135 @@
+use rust_on_rails::prelude::*;
+use crate::elements::shapes::Rectangle;
+use crate::events::{NavigateEvent, ReplaceEvent, PopEvent, PopToRootEvent, TabSelected, OpenTabEvent, CloseDetailEvent, ThemeChanged};
+use crate::layout::{Stack, Bin, Offset, Size, Padding};
+use crate::config::SizeClass;
+use crate::{PelicanUI, AppPage, Pane};
+
+use super::navigation_stack::NavigationStack;
+use super::page_transition::Transition;
+
+/// Sizing of the master/detail split, the main pane gets `ratio` of the width within its bounds.
+#[derive(Clone, Copy, Debug)]
+pub struct Divider {
+    pub width: f32,
+    pub color: Option<Color>,
+    pub ratio: f32,
+    pub min_main: f32,
+    pub min_detail: f32,
+}
+
+impl Default for Divider {
+    fn default() -> Self {
+        Divider{width: 1.0, color: None, ratio: 0.4, min_main: 320.0, min_detail: 375.0}
+    }
+}
+
+/// Keeps a main page on the left and a detail page on the right,
+/// showing a single pane whenever the window is not wide.
+#[derive(Debug)]
+pub struct SplitView(NavigationStack, Option<Box<dyn AppPage>>, Bin<Stack, Rectangle>, Divider, bool);
+
+impl SplitView {
+    pub fn new(ctx: &mut Context, stack: NavigationStack, divider: Divider) -> Self {
+        let state = ctx.get::<PelicanUI>();
+        let color = divider.color.unwrap_or(state.theme.colors.outline.secondary);
+        let collapsed = state.size_class() != SizeClass::Wide;
+        SplitView(
+            stack, None,
+            Bin (
+                Stack(Offset::default(), Offset::default(), Size::Static(divider.width), Size::Fit, Padding::default()),
+                Rectangle::new(color)
+            ),
+            divider, collapsed
+        )
+    }
+
+    pub fn main(&mut self) -> &mut NavigationStack {&mut self.0}
+    pub fn stack(&self) -> &NavigationStack {&self.0}
+    pub fn detail(&mut self) -> &mut Option<Box<dyn AppPage>> {&mut self.1}
+    pub fn is_collapsed(&self) -> bool {self.4}
+
+    /// The history a single pane interface should continue with, an open detail page
+    /// ends up on top of the main page.
+    pub fn into_stack(self, ctx: &mut Context) -> NavigationStack {
+        let SplitView(mut stack, detail, ..) = self;
+        if let Some(detail) = detail {stack.push_page(ctx, detail, Transition::None);}
+        stack
+    }
+
+    fn split(&self, width: f32) -> f32 {
+        let available = width - self.3.width;
+        (available * self.3.ratio).max(self.3.min_main).min(available - self.3.min_detail).max(0.0)
+    }
+}
+
+impl OnEvent for SplitView {
+    fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if let Some(TickEvent) = event.downcast_ref() {
+            self.4 = ctx.get::<PelicanUI>().size_class() != SizeClass::Wide;
+        } else if event.downcast_ref::<ThemeChanged>().is_some() {
+            self.2.inner().shape().color = self.3.color.unwrap_or(ctx.get::<PelicanUI>().theme.colors.outline.secondary);
+        } else if let Some(NavigateEvent(flow)) = event.downcast_ref::<NavigateEvent>() {
+            if flow.pane() == Pane::Detail && !self.0.is_switching() {
+                self.1 = Some(flow.get_page(ctx));
+                return false;
+            }
+            self.1 = None;
+        } else if event.downcast_ref::<PopEvent>().is_some() && self.1.is_some() {
+            self.1 = None;
+            return false;
+        } else if let Some(KeyboardEvent{state: KeyboardState::Pressed, key: Key::Named(NamedKey::Escape)}) = event.downcast_ref() {
+            if self.1.is_some() {
+                ctx.trigger_event(PopEvent);
+                return false;
+            }
+        } else if event.downcast_ref::<CloseDetailEvent>().is_some()
+            || event.downcast_ref::<ReplaceEvent>().is_some()
+            || event.downcast_ref::<PopToRootEvent>().is_some()
+            || event.downcast_ref::<TabSelected>().is_some()
+            || event.downcast_ref::<OpenTabEvent>().is_some() {
+            self.1 = None;
+        }
+        true
+    }
+}
+
+impl Component for SplitView {
+    fn children_mut(&mut self) -> Vec<&mut dyn Drawable> {
+        match (&mut self.1, self.4) {
+            (Some(detail), true) => vec![detail.as_mut()],
+            (Some(detail), false) => vec![&mut self.0, &mut self.2, detail.as_mut()],
+            (None, _) => vec![&mut self.0],
+        }
+    }
+
+    fn children(&self) -> Vec<&dyn Drawable> {
+        match (&self.1, self.4) {
+            (Some(detail), true) => vec![detail.as_ref()],
+            (Some(detail), false) => vec![&self.0, &self.2, detail.as_ref()],
+            (None, _) => vec![&self.0],
+        }
+    }
+
+    fn request_size(&self, _ctx: &mut Context, children: Vec<SizeRequest>) -> SizeRequest {
+        let min_width = match children.len() {
+            3 => children[0].min_width().max(self.3.min_main) + self.3.width + children[2].min_width().max(self.3.min_detail),
+            _ => children[0].min_width(),
+        };
+        let min_height = children.iter().fold(0.0f32, |h, c| h.max(c.min_height()));
+        SizeRequest::new(min_width, min_height, f32::MAX, f32::MAX)
+    }
+
+    fn build(&mut self, _ctx: &mut Context, size: (f32, f32), children: Vec<SizeRequest>) -> Vec<Area> {
+        if children.len() != 3 {
+            return vec![Area{offset: (0.0, 0.0), size: children[0].get(size)}];
+        }
+        let main = self.split(size.0);
+        let detail = (size.0 - main - self.3.width).max(0.0);
+        vec![
+            Area{offset: (0.0, 0.0), size: children[0].get((main, size.1))},
+            Area{offset: (main, 0.0), size: children[1].get((self.3.width, size.1))},
+            Area{offset: (main+self.3.width, 0.0), size: children[2].get((detail, size.1))},
+        ]
+    }
+}
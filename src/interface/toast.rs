@@ -0,0 +1,149 @@
+use rust_on_rails::prelude::*;
+use crate::elements::images::Icon;
+use crate::elements::shapes::OutlinedRectangle;
+use crate::elements::text::{Text, TextStyle};
+use crate::components::button::Button;
+use crate::events::{ShowToast, ToastActionEvent, ThemeChanged};
+use crate::layout::{Row, Stack, Offset, Size, Padding};
+use crate::{PelicanUI, ElementID};
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+const DURATION: Duration = Duration::from_secs(4);
+const ACTION_DURATION: Duration = Duration::from_secs(6); // toasts with a button stay up longer
+const SWIPE: f32 = 80.0; // horizontal distance that dismisses a toast
+const MAX_WIDTH: f32 = 420.0;
+const MARGIN: f32 = 16.0;
+
+/// Shows `ShowToast` requests one at a time above the navigator and bumper.
+#[derive(Debug, Default)]
+pub struct ToastHost {
+    queue: VecDeque<ShowToast>,
+    current: Option<(Toast, Instant, Duration)>,
+    drag: Option<(f32, f32)>,
+    area: ((f32, f32), (f32, f32)),
+    chrome: Option<f32>,
+}
+
+impl ToastHost {
+    pub fn new() -> Self {ToastHost::default()}
+
+    pub fn is_showing(&self) -> bool {self.current.is_some()}
+
+    pub fn dismiss(&mut self) {
+        self.current = None;
+        self.drag = None;
+    }
+}
+
+impl OnEvent for ToastHost {
+    fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if let Some(TickEvent) = event.downcast_ref() {
+            if self.current.as_ref().is_some_and(|(_, shown, duration)| shown.elapsed() >= *duration && self.drag.is_none()) {
+                self.dismiss();
+            }
+            if self.current.is_none() {
+                if let Some(toast) = self.queue.pop_front() {
+                    let duration = if toast.action.is_some() {ACTION_DURATION} else {DURATION};
+                    self.current = Some((Toast::new(ctx, toast), Instant::now(), duration));
+                    ctx.get::<PelicanUI>().overlays.relocate();
+                }
+            }
+            self.chrome = ctx.get::<PelicanUI>().overlays.chrome();
+        } else if let Some(toast) = event.downcast_ref::<ShowToast>() {
+            self.queue.push_back(toast.clone());
+        } else if let Some(ToastActionEvent(id)) = event.downcast_ref::<ToastActionEvent>() {
+            if self.current.as_ref().is_some_and(|(toast, ..)| toast.3 == Some(*id)) {self.dismiss();}
+        } else if let Some(event) = event.downcast_ref::<MouseEvent>() {
+            let (offset, size) = self.area;
+            match event {
+                MouseEvent{state: MouseState::Pressed, position: Some(p)} if self.current.is_some()
+                    && p.0 >= offset.0 && p.0 <= offset.0 + size.0 && p.1 >= offset.1 && p.1 <= offset.1 + size.1 => {
+                    self.drag = Some((p.0, 0.0));
+                },
+                MouseEvent{state: MouseState::Moved, position: Some(p)} => if let Some((start, _)) = self.drag {
+                    self.drag = Some((start, p.0 - start));
+                },
+                MouseEvent{state: MouseState::Released, ..} => match self.drag.take() {
+                    Some((_, dx)) if dx.abs() > SWIPE => self.dismiss(),
+                    _ => {}
+                },
+                _ => {}
+            }
+        }
+        true
+    }
+}
+
+impl Component for ToastHost {
+    fn children_mut(&mut self) -> Vec<&mut dyn Drawable> {
+        self.current.iter_mut().map(|(toast, ..)| toast as &mut dyn Drawable).collect()
+    }
+
+    fn children(&self) -> Vec<&dyn Drawable> {
+        self.current.iter().map(|(toast, ..)| toast as &dyn Drawable).collect()
+    }
+
+    fn request_size(&self, _ctx: &mut Context, _children: Vec<SizeRequest>) -> SizeRequest {
+        SizeRequest::fill()
+    }
+
+    fn build(&mut self, ctx: &mut Context, size: (f32, f32), children: Vec<SizeRequest>) -> Vec<Area> {
+        let bottom = self.chrome.unwrap_or(size.1 - ctx.get::<PelicanUI>().insets().bottom);
+        let dx = self.drag.map(|(_, dx)| dx).unwrap_or_default();
+        children.into_iter().map(|child| {
+            let toast = child.get((MAX_WIDTH.min(size.0 - MARGIN*2.0), size.1));
+            let offset = ((size.0 - toast.0) / 2.0 + dx, bottom - toast.1 - MARGIN);
+            self.area = (offset, toast);
+            Area{offset, size: toast}
+        }).collect()
+    }
+}
+
+#[derive(Debug, Component)]
+struct Toast(Stack, OutlinedRectangle, ToastContent, #[skip] Option<ElementID>);
+
+impl OnEvent for Toast {
+    fn on_event(&mut self, ctx: &mut Context, event: &mut dyn Event) -> bool {
+        if event.downcast_ref::<ThemeChanged>().is_some() {
+            let colors = ctx.get::<PelicanUI>().theme.colors;
+            *self.1.background() = colors.background.secondary;
+            *self.1.outline() = colors.outline.secondary;
+            if let Some(icon) = &mut self.2.1 {icon.color = Some(colors.text.heading);}
+        }
+        true
+    }
+}
+
+impl Toast {
+    fn new(ctx: &mut Context, toast: ShowToast) -> Self {
+        let colors = ctx.get::<PelicanUI>().theme.colors;
+        let background = OutlinedRectangle::new(ctx, colors.background.secondary, colors.outline.secondary, 12.0, 1.0);
+        let layout = Stack(
+            Offset::Center, Offset::Center,
+            Size::custom(|widths: Vec<(f32, f32)>| (widths[1].0, f32::MAX)),
+            Size::custom(|heights: Vec<(f32, f32)>| heights[1]),
+            Padding::default()
+        );
+        let action = toast.action.map(|(_, id)| id);
+        Toast(layout, background, ToastContent::new(ctx, toast), action)
+    }
+}
+
+#[derive(Debug, Component)]
+struct ToastContent(Row, Option<Image>, Text, Option<Button>);
+impl OnEvent for ToastContent {}
+
+impl ToastContent {
+    fn new(ctx: &mut Context, toast: ShowToast) -> Self {
+        let theme = &ctx.get::<PelicanUI>().theme;
+        let (color, font_size) = (theme.colors.text.heading, theme.fonts.size.md);
+        ToastContent(
+            Row(12.0, Offset::Center, Size::Fit, Padding(16.0, 12.0, 8.0, 12.0)),
+            toast.icon.map(|icon| Icon::new(ctx, icon, color, 24.0)),
+            Text::new(ctx, toast.text, TextStyle::Primary, font_size, Align::Left),
+            toast.action.map(|(label, id)| Button::ghost(ctx, label, move |ctx: &mut Context| ctx.trigger_event(ToastActionEvent(id))))
+        )
+    }
+}
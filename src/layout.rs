@@ -224,6 +224,185 @@ impl Layout for Column {
     }
 }
 
+#[derive(Clone, Copy, Default, Debug)]
+pub enum Justify {
+    #[default]
+    Start,
+    Center,
+    End,
+    SpaceBetween,
+    SpaceAround,
+    SpaceEvenly,
+}
+
+impl Justify {
+    // (leading offset, extra spacing between items)
+    pub fn get(&self, free: f32, count: usize) -> (f32, f32) {
+        let free = free.max(0.0);
+        let count = count as f32;
+        match self {
+            Self::Start => (0.0, 0.0),
+            Self::Center => (free / 2.0, 0.0),
+            Self::End => (free, 0.0),
+            Self::SpaceBetween if count > 1.0 => (0.0, free / (count - 1.0)),
+            Self::SpaceBetween => (0.0, 0.0),
+            Self::SpaceAround => (free / count / 2.0, free / count),
+            Self::SpaceEvenly => (free / (count + 1.0), free / (count + 1.0)),
+        }
+    }
+}
+
+/// How a child of a `FlexRow` or `FlexColumn` takes up or gives back main axis space.
+#[derive(Clone, Copy, Debug)]
+pub struct Flex {
+    pub grow: f32,
+    /// Children never shrink below their requested minimum, so shrinking only affects
+    /// children that set a lower `min` or whose request has a range, such as `Size::Fill`.
+    pub shrink: f32,
+    pub min: Option<f32>,
+    pub max: Option<f32>,
+}
+
+impl Default for Flex {
+    fn default() -> Self {
+        Flex{grow: 1.0, shrink: 1.0, min: None, max: None}
+    }
+}
+
+impl Flex {
+    pub fn grow(grow: f32) -> Self {Flex{grow, ..Flex::default()}}
+    pub fn fixed() -> Self {Flex{grow: 0.0, shrink: 0.0, ..Flex::default()}}
+    pub fn bounded(grow: f32, min: Option<f32>, max: Option<f32>) -> Self {Flex{grow, min, max, ..Flex::default()}}
+
+    fn bounds(&self, size: (f32, f32)) -> (f32, f32) {
+        let min = self.min.unwrap_or(size.0);
+        (min, self.max.unwrap_or(size.1).min(size.1).max(min))
+    }
+
+    // (min, max) requested along the main axis by all items
+    fn request(sizes: &[(f32, f32)], items: &[Flex]) -> (f32, f32) {
+        sizes.iter().enumerate().fold((0.0f32, 0.0f32), |(min, max), (i, size)| {
+            let item = items.get(i).copied().unwrap_or_default();
+            let (lo, hi) = item.bounds(*size);
+            let basis = size.0.clamp(lo, hi);
+            (min + if item.shrink > 0.0 {lo} else {basis}, (max + hi).min(f32::MAX))
+        })
+    }
+
+    // Sizes along the main axis and the free space left for justification
+    pub fn get(sizes: Vec<(f32, f32)>, items: &[Flex], max_size: f32, spacing: f32) -> (Vec<f32>, f32) {
+        let spacing = spacing * sizes.len().saturating_sub(1) as f32;
+        let items = (0..sizes.len()).map(|i| items.get(i).copied().unwrap_or_default()).collect::<Vec<_>>();
+        let bounds = sizes.iter().zip(&items).map(|(s, f)| f.bounds(*s)).collect::<Vec<_>>();
+        let mut result = sizes.iter().zip(&bounds).map(|(s, b)| s.0.clamp(b.0, b.1)).collect::<Vec<_>>();
+        let mut free = max_size - spacing - result.iter().sum::<f32>();
+
+        let mut open = (0..result.len()).collect::<Vec<_>>();
+        while free.abs() > 0.01 && !open.is_empty() {
+            let before = free;
+            let growing = free > 0.0;
+            let weight = |i: &usize| if growing {items[*i].grow} else {items[*i].shrink * result[*i]};
+            open.retain(|i| weight(i) > 0.0 && if growing {result[*i] < bounds[*i].1} else {result[*i] > bounds[*i].0});
+            let total = open.iter().map(weight).sum::<f32>();
+            if total <= 0.0 {break;}
+
+            let shares = open.iter().map(|i| (*i, free * weight(i) / total)).collect::<Vec<_>>();
+            shares.into_iter().for_each(|(i, share)| {
+                let size = (result[i] + share).clamp(bounds[i].0, bounds[i].1);
+                free -= size - result[i];
+                result[i] = size;
+            });
+            // Shares too small to change large sizes in f32 would otherwise loop forever
+            if (before - free).abs() < 0.01 {break;}
+        }
+        (result, free.max(0.0))
+    }
+}
+
+#[derive(Debug)]
+pub struct FlexRow(pub f32, pub Offset, pub Size, pub Padding, pub Justify, pub Vec<Flex>);
+// spacing, vertical offset, height, padding, justify, per child flex (missing children use Flex::default)
+
+impl FlexRow {
+    pub fn new(spacing: f32, justify: Justify, items: Vec<Flex>) -> Self {
+        FlexRow(spacing, Offset::Center, Size::Fit, Padding::default(), justify, items)
+    }
+}
+
+impl Layout for FlexRow {
+    fn request_size(&self, _ctx: &mut Context, children: Vec<SizeRequest>) -> SizeRequest {
+        let (widths, heights): (Vec<_>, Vec<_>) = children.into_iter().map(|i|
+            ((i.min_width(), i.max_width()), (i.min_height(), i.max_height()))
+        ).unzip();
+        let spacing = self.0*widths.len().saturating_sub(1) as f32;
+        let width = Flex::request(&widths, &self.5);
+        let height = self.2.get(heights, Size::max);
+        self.3.adjust_request(SizeRequest::new(width.0, height.0, width.1, height.1).add_width(spacing))
+    }
+
+    fn build(&self, _ctx: &mut Context, row_size: (f32, f32), children: Vec<SizeRequest>) -> Vec<Area> {
+        let row_size = self.3.adjust_size(row_size);
+        let (widths, free) = Flex::get(children.iter().map(|i| (i.min_width(), i.max_width())).collect(), &self.5, row_size.0, self.0);
+        let (mut offset, extra) = self.4.get(free, children.len());
+
+        children.into_iter().zip(widths).map(|(i, width)| {
+            let size = i.get((width, row_size.1));
+            let off = self.3.adjust_offset((offset, self.1.get(row_size.1, size.1)));
+            offset += size.0+self.0+extra;
+            Area{offset: off, size}
+        }).collect()
+    }
+}
+
+#[derive(Debug)]
+pub struct FlexColumn(pub f32, pub Offset, pub Size, pub Padding, pub Justify, pub Vec<Flex>);
+// spacing, horizontal offset, width, padding, justify, per child flex (missing children use Flex::default)
+
+impl FlexColumn {
+    pub fn new(spacing: f32, justify: Justify, items: Vec<Flex>) -> Self {
+        FlexColumn(spacing, Offset::Center, Size::Fit, Padding::default(), justify, items)
+    }
+}
+
+impl Layout for FlexColumn {
+    fn request_size(&self, _ctx: &mut Context, children: Vec<SizeRequest>) -> SizeRequest {
+        let (widths, heights): (Vec<_>, Vec<_>) = children.into_iter().map(|i|
+            ((i.min_width(), i.max_width()), (i.min_height(), i.max_height()))
+        ).unzip();
+        let spacing = self.0*heights.len().saturating_sub(1) as f32;
+        let width = self.2.get(widths, Size::max);
+        let height = Flex::request(&heights, &self.5);
+        self.3.adjust_request(SizeRequest::new(width.0, height.0, width.1, height.1).add_height(spacing))
+    }
+
+    fn build(&self, _ctx: &mut Context, col_size: (f32, f32), children: Vec<SizeRequest>) -> Vec<Area> {
+        let col_size = self.3.adjust_size(col_size);
+        let (heights, free) = Flex::get(children.iter().map(|i| (i.min_height(), i.max_height())).collect(), &self.5, col_size.1, self.0);
+        let (mut offset, extra) = self.4.get(free, children.len());
+
+        children.into_iter().zip(heights).map(|(i, height)| {
+            let size = i.get((col_size.0, height));
+            let off = self.3.adjust_offset((self.1.get(col_size.0, size.0), offset));
+            offset += size.1+self.0+extra;
+            Area{offset: off, size}
+        }).collect()
+    }
+}
+
+impl Row {
+    /// Flex version of this row, children without an entry in `items` use Flex::default.
+    pub fn flex(self, justify: Justify, items: Vec<Flex>) -> FlexRow {
+        FlexRow(self.0, self.1, self.2, self.3, justify, items)
+    }
+}
+
+impl Column {
+    /// Flex version of this column, children without an entry in `items` use Flex::default.
+    pub fn flex(self, justify: Justify, items: Vec<Flex>) -> FlexColumn {
+        FlexColumn(self.0, self.1, self.2, self.3, justify, items)
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct Stack(pub Offset, pub Offset, pub Size, pub Size, pub Padding);
 impl Stack {
@@ -257,51 +436,338 @@ impl Layout for Stack {
 }
 
 
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub enum Flow {
+    #[default]
+    LeftToRight,
+    RightToLeft,
+}
+
 #[derive(Debug)]
 pub struct Wrap(pub f32, pub f32, pub Offset, pub Offset, pub Padding);
-// width spacing, height spacing, vertical offset, horizontal offset, width, padding
+// width spacing, height spacing, vertical offset, horizontal offset, padding
+// Lines start at the left edge with items at the top of their line, the offsets only apply through `align_lines` or `flow`.
+// Lines break at the width of page content, see `FlowWrap`.
 impl Wrap {
     pub fn center(w_spacing: f32, h_spacing: f32) -> Self {
         Wrap(w_spacing, h_spacing, Offset::Center, Offset::Center, Padding::default())
     }
+
+    pub fn start(w_spacing: f32, h_spacing: f32) -> Self {
+        Wrap(w_spacing, h_spacing, Offset::Center, Offset::Start, Padding::default())
+    }
+
+    /// Aligns each line by the horizontal offset and the items within a line by the vertical offset.
+    pub fn align_lines(self) -> FlowWrap {
+        self.flow(Size::Fit, Flow::LeftToRight, false)
+    }
+
+    /// Flow version of this wrap with a width to break lines at and a direction, lines are aligned like `align_lines`.
+    pub fn flow(self, width: Size, flow: Flow, reversed: bool) -> FlowWrap {
+        FlowWrap(self.0, self.1, self.2, self.3, width, self.4, flow, reversed)
+    }
+
+    fn as_flow(&self) -> FlowWrap {
+        FlowWrap(self.0, self.1, Offset::Start, Offset::Start, Size::Fit, self.4.clone(), Flow::LeftToRight, false)
+    }
 }
 
 impl Layout for Wrap {
-    fn request_size(&self, _ctx: &mut Context, children: Vec<SizeRequest>) -> SizeRequest {
-        let ((min_w, max_w), (min_h, max_h)): ((Vec<_>, Vec<_>), (Vec<_>, Vec<_>)) = children.into_iter().map(|i|
-            ((i.min_width(), i.max_width()), (i.min_height(), i.max_height()))
-        ).unzip();
+    fn request_size(&self, ctx: &mut Context, children: Vec<SizeRequest>) -> SizeRequest {
+        self.as_flow().request_size(ctx, children)
+    }
 
-        let w_spacing = self.0*(min_w.len()-1) as f32;
-        let h_spacing = self.1*(min_h.len()-1) as f32;
+    fn build(&self, ctx: &mut Context, maximum_size: (f32, f32), children: Vec<SizeRequest>) -> Vec<Area> {
+        self.as_flow().build(ctx, maximum_size, children)
+    }
+}
+
+#[derive(Debug)]
+pub struct FlowWrap(pub f32, pub f32, pub Offset, pub Offset, pub Size, pub Padding, pub Flow, pub bool);
+// width spacing, height spacing, vertical offset, horizontal offset, width, padding, flow, reversed
+// The requested height has lines broken at the largest width `width` allows. Size::Fit breaks at the
+// width of page content for the current window, give a width when the wrap sits somewhere narrower.
+// Build always breaks at the width it gets.
+impl FlowWrap {
+    fn order(&self, count: usize) -> Vec<usize> {
+        match self.7 {
+            true => (0..count).rev().collect(),
+            false => (0..count).collect()
+        }
+    }
 
-        let min_width = min_w.into_iter().reduce(|s, i| s.max(i)).unwrap_or_default();
-        let max_width = max_w.into_iter().reduce(|s, i| s+i).unwrap_or_default();
+    // Breaks the ordered items into lines no wider than max_width, an item wider than that gets a line of its own
+    fn lines(order: &[usize], widths: &[f32], spacing: f32, max_width: f32) -> Vec<Vec<usize>> {
+        let mut lines: Vec<Vec<usize>> = vec![];
+        let mut taken = 0.0;
+        order.iter().for_each(|i| {
+            match lines.last_mut() {
+                Some(line) if taken + spacing + widths[*i] <= max_width => {
+                    taken += spacing + widths[*i];
+                    line.push(*i);
+                },
+                _ => {
+                    taken = widths[*i];
+                    lines.push(vec![*i]);
+                }
+            }
+        });
+        lines
+    }
 
-        let min_height = min_h.into_iter().reduce(|s, i| s.max(i)).unwrap_or_default();
-        let max_height = max_h.into_iter().sum();
+    fn line_height(line: &[usize], heights: &[f32]) -> f32 {
+        line.iter().map(|i| heights[*i]).fold(0.0, f32::max)
+    }
 
-        // println!("min: {:?} max: {:?}", min_height, max_height);
+    fn height(lines: &[Vec<usize>], heights: &[f32], spacing: f32) -> f32 {
+        lines.iter().map(|line| FlowWrap::line_height(line, heights)).sum::<f32>()
+            + spacing*lines.len().saturating_sub(1) as f32
+    }
+}
 
-        self.4.adjust_request(SizeRequest::new(min_width, min_height, max_width, max_height).add(w_spacing, h_spacing))
+impl Layout for FlowWrap {
+    fn request_size(&self, ctx: &mut Context, children: Vec<SizeRequest>) -> SizeRequest {
+        let widths = children.iter().map(|i| i.min_width()).collect::<Vec<_>>();
+        let heights = children.iter().map(|i| i.min_height()).collect::<Vec<_>>();
+
+        let widest = widths.iter().copied().fold(0.0, f32::max);
+        let line = widths.iter().sum::<f32>() + self.0*widths.len().saturating_sub(1) as f32;
+        let (min, max) = match self.4 {
+            Size::Fit => (widest, crate::interface::Content::width(ctx) - self.5.0 - self.5.2),
+            ref size => size.get(widths.iter().map(|w| (*w, *w)).collect(), Size::max),
+        };
+        let (min, max) = (min.max(widest), max.min(line).max(min.max(widest)));
+
+        let lines = FlowWrap::lines(&self.order(children.len()), &widths, self.0, max);
+        let height = FlowWrap::height(&lines, &heights, self.1);
+        self.5.adjust_request(SizeRequest::new(min, height, max, height))
     }
 
     fn build(&self, _ctx: &mut Context, maximum_size: (f32, f32), children: Vec<SizeRequest>) -> Vec<Area> {
-        let mut taken_width = self.4.1;
-        let mut height_offset = self.4.2;
-        let mut items: Vec<SizeRequest> = Vec::new();
-        children.iter().map(|child| {
-            if (taken_width + child.min_width()) > maximum_size.0 {
-                let heights: Vec<_> = items.iter().map(|c| c.min_height()).collect();
-                height_offset += heights.into_iter().reduce(|s, i| s.max(i)).unwrap_or_default() + self.1;
-                taken_width = self.4.1;
-            };
-
-            let area = Area {offset: (taken_width, height_offset), size: (child.min_width(), child.min_height())};
-            // println!("area: {:?}", area);
-            taken_width += child.min_width() + self.0; 
-            items.push(*child);
-            area
+        let size = self.5.adjust_size(maximum_size);
+        let widths = children.iter().map(|i| i.min_width()).collect::<Vec<_>>();
+        let heights = children.iter().map(|i| i.min_height()).collect::<Vec<_>>();
+
+        let lines = FlowWrap::lines(&self.order(children.len()), &widths, self.0, size.0);
+        let mut areas: Vec<Option<Area>> = children.iter().map(|_| None).collect();
+        let mut y = 0.0;
+
+        lines.into_iter().for_each(|line| {
+            let height = FlowWrap::line_height(&line, &heights);
+            let width = line.iter().map(|i| widths[*i]).sum::<f32>() + self.0*(line.len()-1) as f32;
+            let mut x = self.3.get(size.0, width);
+
+            line.into_iter().for_each(|i| {
+                let item = children[i].get((widths[i], height));
+                let offset = match self.6 {
+                    Flow::LeftToRight => x,
+                    Flow::RightToLeft => size.0 - x - item.0,
+                };
+                areas[i] = Some(Area{offset: self.5.adjust_offset((offset, y + self.2.get(height, item.1))), size: item});
+                x += item.0 + self.0;
+            });
+            y += height + self.1;
+        });
+        areas.into_iter().flatten().collect()
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum Track {
+    Static(f32),
+    Fraction(f32),
+    Fit,
+}
+
+impl Track {
+    pub fn fill() -> Self {Track::Fraction(1.0)}
+
+    // sizes: per track (min, max) of the single span items it holds
+    fn bounds(&self, sizes: Option<(f32, f32)>) -> (f32, f32) {
+        let (min, max) = sizes.unwrap_or_default();
+        match self {
+            Track::Static(s) => (*s, *s),
+            Track::Fraction(_) => (min, f32::MAX),
+            Track::Fit => (min, max.max(min)),
+        }
+    }
+
+    fn resolve(tracks: &[Track], items: &[(usize, usize, f32, f32)], max_size: f32, gap: f32) -> Vec<f32> {
+        if tracks.is_empty() {return vec![];}
+        let mut bounds = Track::measure(tracks, items);
+
+        let gaps = gap * (tracks.len()-1) as f32;
+        let fractions = tracks.iter().enumerate().filter_map(|(i, t)| match t {
+            Track::Fraction(f) => Some((i, *f)),
+            _ => None
+        }).collect::<Vec<_>>();
+
+        if fractions.is_empty() {
+            // No fractional tracks, fit tracks expand towards their content like a Row
+            return UniformExpand::get(bounds, max_size, gap);
+        }
+
+        // Fit tracks grow towards their content first, leaving the fractions at least their minimum
+        let rigid = (0..tracks.len()).filter(|i| !fractions.iter().any(|(f, _)| f == i)).collect::<Vec<_>>();
+        if !rigid.is_empty() {
+            let reserved = fractions.iter().fold(0.0, |s, (i, _)| s+bounds[*i].0);
+            let sizes = UniformExpand::get(rigid.iter().map(|i| bounds[*i]).collect(), (max_size - reserved - gaps).max(0.0), 0.0);
+            rigid.iter().zip(sizes).for_each(|(i, size)| bounds[*i].0 = size);
+        }
+
+        let fixed = rigid.iter().fold(0.0, |s, i| s+bounds[*i].0);
+        let mut free = (max_size - fixed - gaps).max(0.0);
+        let mut open = fractions;
+
+        // Give each fraction its share of the free space, freezing tracks whose content is larger
+        loop {
+            let weight = open.iter().fold(0.0, |s, (_, f)| s+f);
+            // Zero weight fractions only keep their content size
+            let share = |f: f32| if weight > 0.0 {free * f / weight} else {0.0};
+            let frozen = open.iter().filter(|(i, f)| bounds[*i].0 > share(*f)).map(|(i, _)| *i).collect::<Vec<_>>();
+            if frozen.is_empty() || frozen.len() == open.len() {
+                open.iter().for_each(|(i, f)| bounds[*i].0 = bounds[*i].0.max(share(*f)));
+                break;
+            }
+            free = (free - frozen.iter().fold(0.0, |s, i| s+bounds[*i].0)).max(0.0);
+            open.retain(|(i, _)| !frozen.contains(i));
+        }
+        bounds.into_iter().map(|b| b.0).collect()
+    }
+
+    // items: (start, span, min, max)
+    fn measure(tracks: &[Track], items: &[(usize, usize, f32, f32)]) -> Vec<(f32, f32)> {
+        let mut bounds = tracks.iter().enumerate().map(|(i, track)| {
+            let sizes = items.iter().filter(|it| it.0 == i && it.1 == 1).map(|it| (it.2, it.3)).collect::<Vec<_>>();
+            track.bounds((!sizes.is_empty()).then(|| Size::max(sizes)))
+        }).collect::<Vec<_>>();
+
+        // Spanning items spread whatever they are missing across the non static tracks they cover
+        items.iter().filter(|it| it.1 > 1).for_each(|(start, span, min, _)| {
+            let end = (start+span).min(tracks.len());
+            let covered = bounds[*start..end].iter().fold(0.0, |s, b| s+b.0);
+            let growable = (*start..end).filter(|i| !matches!(tracks[*i], Track::Static(_))).collect::<Vec<_>>();
+            if *min > covered && !growable.is_empty() {
+                let extra = (min - covered) / growable.len() as f32;
+                growable.into_iter().for_each(|i| {
+                    bounds[i].0 += extra;
+                    bounds[i].1 = bounds[i].1.max(bounds[i].0);
+                });
+            }
+        });
+        bounds
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct GridCell {
+    pub column: usize,
+    pub row: usize,
+    pub column_span: usize,
+    pub row_span: usize,
+    pub offset: (Offset, Offset),
+}
+
+impl GridCell {
+    pub fn new(column: usize, row: usize) -> Self {
+        GridCell{column, row, column_span: 1, row_span: 1, offset: (Offset::Center, Offset::Center)}
+    }
+
+    pub fn span(column: usize, row: usize, column_span: usize, row_span: usize) -> Self {
+        GridCell{column_span: column_span.max(1), row_span: row_span.max(1), ..GridCell::new(column, row)}
+    }
+
+    pub fn aligned(column: usize, row: usize, horizontal: Offset, vertical: Offset) -> Self {
+        GridCell{offset: (horizontal, vertical), ..GridCell::new(column, row)}
+    }
+}
+
+#[derive(Debug)]
+pub struct Grid(pub Vec<Track>, pub Vec<Track>, pub (f32, f32), pub Vec<GridCell>, pub Padding);
+// columns, rows, (column spacing, row spacing), cells, padding
+// Children without a cell are placed row by row, rows past the given tracks are Track::Fit
+impl Grid {
+    pub fn new(columns: Vec<Track>, spacing: (f32, f32)) -> Self {
+        Grid(columns, vec![], spacing, vec![], Padding::default())
+    }
+
+    pub fn uniform(columns: usize, spacing: (f32, f32)) -> Self {
+        Grid::new(vec![Track::fill(); columns], spacing)
+    }
+
+    fn cell(&self, index: usize) -> GridCell {
+        self.3.get(index).copied().unwrap_or_else(|| {
+            let columns = self.0.len().max(1);
+            GridCell::new(index % columns, index / columns)
+        })
+    }
+
+    fn tracks(&self, children: &[SizeRequest]) -> (Vec<Track>, Vec<Track>, Vec<GridCell>) {
+        let cells = (0..children.len()).map(|i| self.cell(i)).collect::<Vec<_>>();
+        let width = cells.iter().map(|c| c.column+c.column_span).max().unwrap_or_default();
+        let height = cells.iter().map(|c| c.row+c.row_span).max().unwrap_or_default();
+        let mut columns = self.0.clone();
+        let mut rows = self.1.clone();
+        columns.resize(columns.len().max(width), Track::Fit);
+        rows.resize(rows.len().max(height), Track::Fit);
+        (columns, rows, cells)
+    }
+
+    fn items(cells: &[GridCell], children: &[SizeRequest]) -> (Vec<(usize, usize, f32, f32)>, Vec<(usize, usize, f32, f32)>) {
+        cells.iter().zip(children).map(|(c, i)| (
+            (c.column, c.column_span, i.min_width(), i.max_width()),
+            (c.row, c.row_span, i.min_height(), i.max_height())
+        )).unzip()
+    }
+
+    fn span_size(sizes: &[f32], start: usize, span: usize, spacing: f32) -> f32 {
+        let end = (start+span).min(sizes.len());
+        sizes[start..end].iter().sum::<f32>() + spacing * (end-start).saturating_sub(1) as f32
+    }
+
+    fn request(tracks: &[Track], items: &[(usize, usize, f32, f32)], spacing: f32) -> (f32, f32) {
+        if tracks.is_empty() {return (0.0, 0.0);}
+        let spacing = spacing * (tracks.len()-1) as f32;
+        let (min, max) = Size::add(Track::measure(tracks, items));
+        (min+spacing, (max+spacing).min(f32::MAX))
+    }
+}
+
+impl Layout for Grid {
+    fn request_size(&self, _ctx: &mut Context, children: Vec<SizeRequest>) -> SizeRequest {
+        let (columns, rows, cells) = self.tracks(&children);
+        let (widths, heights) = Grid::items(&cells, &children);
+        let width = Grid::request(&columns, &widths, self.2.0);
+        let height = Grid::request(&rows, &heights, self.2.1);
+        self.4.adjust_request(SizeRequest::new(width.0, height.0, width.1, height.1))
+    }
+
+    fn build(&self, _ctx: &mut Context, grid_size: (f32, f32), children: Vec<SizeRequest>) -> Vec<Area> {
+        let grid_size = self.4.adjust_size(grid_size);
+        let (columns, rows, cells) = self.tracks(&children);
+        let (widths, heights) = Grid::items(&cells, &children);
+
+        let widths = Track::resolve(&columns, &widths, grid_size.0, self.2.0);
+        let heights = Track::resolve(&rows, &heights, grid_size.1, self.2.1);
+
+        let starts = |sizes: &[f32], spacing: f32| sizes.iter().scan(0.0, |s, i| {
+            let start = *s;
+            *s += i+spacing;
+            Some(start)
+        }).collect::<Vec<_>>();
+        let (x_starts, y_starts) = (starts(&widths, self.2.0), starts(&heights, self.2.1));
+
+        children.into_iter().zip(cells).map(|(i, cell)| {
+            let cell_size = (
+                Grid::span_size(&widths, cell.column, cell.column_span, self.2.0),
+                Grid::span_size(&heights, cell.row, cell.row_span, self.2.1)
+            );
+            let size = i.get(cell_size);
+            let offset = (
+                x_starts[cell.column] + cell.offset.0.get(cell_size.0, size.0),
+                y_starts[cell.row] + cell.offset.1.get(cell_size.1, size.1)
+            );
+            Area{offset: self.4.adjust_offset(offset), size}
         }).collect()
     }
 }
@@ -354,3 +820,141 @@ impl<L: Drawable + 'static, R: Drawable + 'static> EitherOr<L, R> {
     pub fn left(&mut self) -> &mut L {self.1.inner()}
     pub fn right(&mut self) -> &mut R {self.2.inner()}
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx(a: &[f32], b: &[f32]) -> bool {
+        a.len() == b.len() && a.iter().zip(b).all(|(a, b)| (a - b).abs() < 0.01)
+    }
+
+    #[test]
+    fn flex_grows_by_weight() {
+        let (sizes, free) = Flex::get(vec![(0.0, f32::MAX); 2], &[Flex::grow(1.0), Flex::grow(2.0)], 300.0, 0.0);
+        assert!(approx(&sizes, &[100.0, 200.0]), "{:?}", sizes);
+        assert_eq!(free, 0.0);
+    }
+
+    #[test]
+    fn flex_max_passes_space_on() {
+        let items = [Flex::bounded(1.0, None, Some(50.0))];
+        let (sizes, _) = Flex::get(vec![(0.0, f32::MAX); 2], &items, 310.0, 10.0);
+        assert!(approx(&sizes, &[50.0, 250.0]), "{:?}", sizes);
+    }
+
+    #[test]
+    fn flex_fixed_leaves_free_space() {
+        let (sizes, free) = Flex::get(vec![(100.0, f32::MAX)], &[Flex::fixed()], 300.0, 0.0);
+        assert!(approx(&sizes, &[100.0]), "{:?}", sizes);
+        assert!((free - 200.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn flex_shrinks_down_to_min() {
+        let items = [Flex::bounded(1.0, Some(0.0), None); 2];
+        let (sizes, _) = Flex::get(vec![(200.0, 200.0); 2], &items, 300.0, 0.0);
+        assert!(approx(&sizes, &[150.0, 150.0]), "{:?}", sizes);
+    }
+
+    #[test]
+    fn flex_does_not_shrink_below_request() {
+        let (sizes, free) = Flex::get(vec![(200.0, 200.0); 2], &[], 300.0, 0.0);
+        assert!(approx(&sizes, &[200.0, 200.0]), "{:?}", sizes);
+        assert_eq!(free, 0.0);
+    }
+
+    #[test]
+    fn flex_stops_when_shares_round_away() {
+        // Above 2^24 f32 steps by 2, so the last fraction of a point can never be handed out
+        let (sizes, _) = Flex::get(vec![(16777216.0, f32::MAX), (0.0, 0.75)], &[], 16777218.0, 0.0);
+        assert_eq!(sizes[1], 0.75);
+        assert!(sizes[0] >= 16777216.0);
+    }
+
+    #[test]
+    fn justify_spreads_free_space() {
+        assert_eq!(Justify::SpaceBetween.get(90.0, 4), (0.0, 30.0));
+        assert_eq!(Justify::SpaceEvenly.get(100.0, 4), (20.0, 20.0));
+        assert_eq!(Justify::End.get(-10.0, 2), (0.0, 0.0));
+    }
+
+    #[test]
+    fn wrap_breaks_lines_at_width() {
+        let lines = FlowWrap::lines(&[0, 1, 2, 3], &[40.0, 40.0, 40.0, 100.0], 10.0, 100.0);
+        assert_eq!(lines, vec![vec![0, 1], vec![2], vec![3]]);
+    }
+
+    #[test]
+    fn wrap_gives_oversized_items_their_own_line() {
+        let lines = FlowWrap::lines(&[0, 1, 2], &[20.0, 150.0, 20.0], 0.0, 100.0);
+        assert_eq!(lines, vec![vec![0], vec![1], vec![2]]);
+    }
+
+    #[test]
+    fn wrap_height_uses_tallest_item_per_line() {
+        let lines = vec![vec![0, 1], vec![2]];
+        assert_eq!(FlowWrap::height(&lines, &[10.0, 30.0, 20.0], 8.0), 58.0);
+        assert_eq!(FlowWrap::height(&[], &[], 8.0), 0.0);
+    }
+
+    #[test]
+    fn wrap_lines_start_unless_aligned() {
+        assert!(matches!(Wrap::center(8.0, 8.0).as_flow(), FlowWrap(_, _, Offset::Start, Offset::Start, Size::Fit, ..)));
+        assert!(matches!(Wrap::center(8.0, 8.0).align_lines(), FlowWrap(_, _, Offset::Center, Offset::Center, Size::Fit, ..)));
+    }
+
+    #[test]
+    fn static_and_fraction_tracks() {
+        let sizes = Track::resolve(&[Track::Static(100.0), Track::fill()], &[], 300.0, 0.0);
+        assert!(approx(&sizes, &[100.0, 200.0]), "{:?}", sizes);
+    }
+
+    #[test]
+    fn fractions_share_by_weight_after_gaps() {
+        let sizes = Track::resolve(&[Track::Fraction(1.0), Track::Fraction(2.0)], &[], 310.0, 10.0);
+        assert!(approx(&sizes, &[100.0, 200.0]), "{:?}", sizes);
+    }
+
+    #[test]
+    fn fraction_with_larger_content_is_frozen() {
+        let items = [(0, 1, 200.0, 300.0)];
+        let sizes = Track::resolve(&[Track::fill(), Track::fill()], &items, 300.0, 0.0);
+        assert!(approx(&sizes, &[200.0, 100.0]), "{:?}", sizes);
+    }
+
+    #[test]
+    fn fit_track_grows_to_content_before_fractions() {
+        let items = [(0, 1, 50.0, 80.0)];
+        let sizes = Track::resolve(&[Track::Fit, Track::fill()], &items, 300.0, 0.0);
+        assert!(approx(&sizes, &[80.0, 220.0]), "{:?}", sizes);
+    }
+
+    #[test]
+    fn fit_track_stays_at_min_without_space() {
+        let items = [(0, 1, 50.0, 80.0), (1, 1, 40.0, 40.0)];
+        let sizes = Track::resolve(&[Track::Fit, Track::fill()], &items, 90.0, 0.0);
+        assert!(approx(&sizes, &[50.0, 40.0]), "{:?}", sizes);
+    }
+
+    #[test]
+    fn fit_tracks_expand_uniformly_without_fractions() {
+        let items = [(0, 1, 50.0, 100.0), (1, 1, 50.0, 100.0)];
+        let sizes = Track::resolve(&[Track::Fit, Track::Fit], &items, 150.0, 0.0);
+        assert!(approx(&sizes, &[75.0, 75.0]), "{:?}", sizes);
+    }
+
+    #[test]
+    fn zero_weight_fractions_keep_their_content() {
+        let items = [(0, 1, 30.0, 60.0)];
+        let sizes = Track::resolve(&[Track::Fraction(0.0), Track::Fraction(0.0)], &items, 300.0, 10.0);
+        assert!(approx(&sizes, &[30.0, 0.0]), "{:?}", sizes);
+    }
+
+    #[test]
+    fn spanning_item_spreads_over_growable_tracks() {
+        let items = [(0, 3, 120.0, 120.0)];
+        let bounds = Track::measure(&[Track::Fit, Track::Static(20.0), Track::Fit], &items);
+        assert!(approx(&bounds.iter().map(|b| b.0).collect::<Vec<_>>(), &[50.0, 20.0, 50.0]), "{:?}", bounds);
+    }
+}
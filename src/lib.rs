@@ -7,7 +7,9 @@ pub mod components;
 pub mod interface;
 
 use rust_on_rails::prelude::*;
-use crate::theme::Theme;
+use crate::theme::{Theme, ThemePreference};
+use crate::interface::Overlays;
+use crate::config::{SizeClass, Insets, SafeArea, Accessibility};
 
 #[cfg(target_os = "ios")]
 extern "C" {
@@ -23,11 +25,72 @@ fn vibrate()  {
 
 pub struct PelicanUI {
     pub theme: Theme,
+    pub overlays: Overlays,
+    /// Page transitions and other animations, turn off for tests. Users reduce motion through `Accessibility`.
+    pub animations: bool,
+    window: (f32, f32),
+    size_class: SizeClass,
+    system_dark: bool,
+    safe_area: Box<dyn SafeArea>,
+    insets: Insets,
+    accessibility: Accessibility,
 }
 
 impl PelicanUI {
     pub fn init(&mut self, theme: Theme) {
         self.theme = theme;
+        self.theme.high_contrast = self.accessibility.high_contrast;
+        self.theme.resolve(self.system_dark);
+    }
+
+    /// Switches palettes at runtime, themed components recolor on the `ThemeChanged` that follows.
+    pub fn set_theme_preference(ctx: &mut Context, preference: ThemePreference) {
+        let ui = ctx.get::<PelicanUI>();
+        ui.theme.preference = preference;
+        ui.theme.resolve(ui.system_dark);
+        ctx.trigger_event(crate::events::ThemeChanged);
+    }
+
+    /// Reports the OS appearance, it only changes the colors while the preference is `System`.
+    pub fn set_system_dark(ctx: &mut Context, dark: bool) {
+        let ui = ctx.get::<PelicanUI>();
+        ui.system_dark = dark;
+        if ui.theme.preference == ThemePreference::System {
+            ui.theme.resolve(dark);
+            ctx.trigger_event(crate::events::ThemeChanged);
+        }
+    }
+
+    /// Applies accessibility preferences, triggering `ThemeChanged` and then `AccessibilityChanged`.
+    pub fn set_accessibility(ctx: &mut Context, accessibility: Accessibility) {
+        let ui = ctx.get::<PelicanUI>();
+        ui.accessibility = accessibility;
+        ui.theme.high_contrast = accessibility.high_contrast;
+        ui.theme.resolve(ui.system_dark);
+        ctx.trigger_event(crate::events::ThemeChanged);
+        ctx.trigger_event(crate::events::AccessibilityChanged(accessibility));
+    }
+
+    pub fn accessibility(&self) -> Accessibility {self.accessibility}
+
+    /// Whether components should animate, false when animations are off or the user reduces motion.
+    pub fn animate(&self) -> bool {self.animations && !self.accessibility.reduce_motion}
+
+    pub fn window(&self) -> (f32, f32) {self.window}
+    pub fn size_class(&self) -> SizeClass {self.size_class}
+    pub fn insets(&self) -> Insets {self.insets}
+
+    /// Replaces the platform insets, e.g. with a `StaticSafeArea` to simulate a notch on desktop.
+    pub fn set_safe_area(&mut self, safe_area: impl SafeArea) {
+        self.safe_area = Box::new(safe_area);
+        self.insets = self.safe_area.insets(self.window);
+    }
+
+    pub(crate) fn resize(&mut self, window: (f32, f32)) {
+        if window != self.window {self.overlays.relocate();}
+        self.window = window;
+        self.size_class = SizeClass::from_width(window.0);
+        self.insets = self.safe_area.insets(window);
     }
 }
 
@@ -36,15 +99,46 @@ impl Plugin for PelicanUI {
 
     async fn new(ctx: &mut Context, _h_ctx: &mut HeadlessContext) -> (Self, Tasks) {
         ctx.include_assets(include_assets!("./resources"));
-        (PelicanUI{theme: Theme::default(ctx)}, vec![])
+        let size_class = if config::IS_MOBILE {SizeClass::Compact} else {SizeClass::Regular};
+        #[cfg(target_os = "ios")]
+        let safe_area: Box<dyn SafeArea> = Box::new(config::NativeSafeArea);
+        #[cfg(not(target_os = "ios"))]
+        let safe_area: Box<dyn SafeArea> = Box::new(config::StaticSafeArea::default());
+        (PelicanUI{
+            theme: Theme::default(ctx), overlays: Overlays::default(), animations: true,
+            window: (0.0, 0.0), size_class, system_dark: true, safe_area, insets: Insets::default(),
+            accessibility: Accessibility::default()
+        }, vec![])
     }
 }
 
-pub trait AppPage: Drawable + std::fmt::Debug + 'static {}
+pub trait AppPage: Drawable + std::fmt::Debug + 'static {
+    /// Whether the mobile tab navigator is shown with this page.
+    /// Pages that hide it override this, e.g. with the `navigator_status` of a wrapped `Page`.
+    fn has_nav(&self) -> bool {true}
+}
+
+/// Side of a split view a flow opens in, single pane interfaces ignore it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Pane {
+    #[default]
+    Main,
+    Detail,
+}
 
 pub trait AppFlow: std::fmt::Debug + Send + Sync + dyn_clone::DynClone + 'static {
     fn get_page(&self, ctx: &mut Context) -> Box<dyn crate::AppPage>;
 
+    fn pane(&self) -> Pane {Pane::Main}
+
+    fn transition(&self) -> crate::interface::Transition {crate::interface::Transition::Slide}
+
+    /// Name this flow is registered under in a `FlowRegistry`, flows without one are not saved.
+    fn name(&self) -> Option<&'static str> {None}
+
+    /// Data needed to rebuild this flow from its registered name.
+    fn payload(&self) -> serde_json::Value {serde_json::Value::Null}
+
     fn navigate(self, ctx: &mut Context) where Self: Sized {
         ctx.trigger_event(crate::events::NavigateEvent(Box::new(self) as Box<dyn AppFlow>));
     }
@@ -69,11 +163,13 @@ pub mod prelude {
     pub use crate::ElementID;
     pub use crate::AppFlow;
     pub use crate::AppPage;
+    pub use crate::Pane;
     pub use crate::events::*;
     pub use crate::interface::*;
     pub use crate::layout::*;
     pub use crate::components::*;
     pub use crate::elements::*;
-    pub use crate::theme::Theme;
+    pub use crate::theme::{Theme, ThemePreference, ThemeError, ContrastReport, ContrastPair};
     pub use crate::PelicanUI;
+    pub use crate::config::{SizeClass, Insets, SafeArea, StaticSafeArea, Accessibility};
 }
@@ -1,4 +1,4 @@
-use rust_on_rails::prelude::Context;
+use rust_on_rails::prelude::{Context, Color};
 
 use colors::ColorResources;
 use fonts::FontResources;
@@ -9,28 +9,77 @@ pub mod colors;
 pub mod fonts;
 pub mod icons;
 pub mod brand;
+pub mod tokens;
+pub mod audit;
+
+pub use tokens::ThemeError;
+pub use audit::{ContrastReport, ContrastPair};
+
+/// Which palette `Theme::colors` resolves to, `System` follows the OS appearance.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ThemePreference {
+    #[default]
+    System,
+    Light,
+    Dark,
+}
 
 pub struct Theme {
     pub colors: ColorResources,
     pub fonts: FontResources,
     pub icons: IconResources,
     pub brand: BrandResources,
+    pub light: ColorResources,
+    pub dark: ColorResources,
+    pub preference: ThemePreference,
+    /// Applies `ColorResources::high_contrast` to the resolved palette.
+    pub high_contrast: bool,
 }
 
 impl Theme {
     pub fn default(ctx: &mut Context) -> Self {
         Theme {
-            colors: ColorResources::default(),
+            colors: ColorResources::dark(),
             fonts: FontResources::default(ctx),
             icons: IconResources::default(ctx),
-            brand: BrandResources::default(ctx)
+            brand: BrandResources::default(ctx),
+            light: ColorResources::light(),
+            dark: ColorResources::dark(),
+            preference: ThemePreference::System,
+            high_contrast: false,
         }
     }
 
+    /// Uses `colors` for both appearances, add a second palette with `palettes`.
     pub fn new(
         colors: ColorResources, 
         fonts: FontResources, 
         icons: IconResources,
         brand: BrandResources,
-    ) -> Self { Theme { colors, fonts, icons, brand } }
+    ) -> Self { Theme { colors, fonts, icons, brand, light: colors, dark: colors, preference: ThemePreference::System, high_contrast: false } }
+
+    /// Replaces both palettes with ones generated from `brand`, see `ColorResources::generate`.
+    pub fn brand_colors(self, brand: Color, neutral: Option<Color>) -> Self {
+        self.palettes(ColorResources::generate(brand, neutral, false), ColorResources::generate(brand, neutral, true))
+    }
+
+    pub fn palettes(mut self, light: ColorResources, dark: ColorResources) -> Self {
+        self.light = light;
+        self.dark = dark;
+        self
+    }
+
+    pub fn is_dark(&self, system_dark: bool) -> bool {
+        match self.preference {
+            ThemePreference::System => system_dark,
+            ThemePreference::Light => false,
+            ThemePreference::Dark => true,
+        }
+    }
+
+    /// Points `colors` at the palette for the preference and system appearance.
+    pub fn resolve(&mut self, system_dark: bool) {
+        let colors = if self.is_dark(system_dark) {self.dark} else {self.light};
+        self.colors = if self.high_contrast {colors.high_contrast()} else {colors};
+    }
 }
@@ -0,0 +1,183 @@
+use rust_on_rails::prelude::*;
+
+use super::Theme;
+use super::colors::{ColorResources, ButtonColorScheme, contrast, over};
+use crate::elements::text::TextStyle;
+use crate::components::avatar::AvatarIconStyle;
+
+// WCAG 2.x minimums for (AA, AAA). Icons are held to the large text and non-text thresholds.
+const TEXT: (f32, f32) = (4.5, 7.0);
+const ICON: (f32, f32) = (3.0, 4.5);
+
+/// One foreground over background pairing used by a component.
+#[derive(Debug, Clone)]
+pub struct ContrastPair {
+    pub name: String,
+    pub foreground: Color,
+    pub background: Color,
+    pub ratio: f32,
+    pub aa: bool,
+    pub aaa: bool,
+}
+
+impl ContrastPair {
+    fn new(name: impl Into<String>, foreground: Color, background: Color, (aa, aaa): (f32, f32)) -> Self {
+        let ratio = contrast(foreground, background);
+        ContrastPair{name: name.into(), foreground, background, ratio, aa: ratio >= aa, aaa: ratio >= aaa}
+    }
+}
+
+impl std::fmt::Display for ContrastPair {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let grade = if self.aaa {"AAA"} else if self.aa {"AA"} else {"fail"};
+        write!(f, "{}: {:.2}:1 ({})", self.name, self.ratio, grade)
+    }
+}
+
+/// Contrast of every pairing the components draw from a palette.
+///
+/// Disabled buttons are exempt from WCAG and kept apart in the second list, out of `failures` and the pass checks.
+#[derive(Debug, Clone, Default)]
+pub struct ContrastReport(pub Vec<ContrastPair>, pub Vec<ContrastPair>);
+
+impl ContrastReport {
+    pub fn audit(colors: &ColorResources) -> Self {
+        let (mut pairs, mut disabled) = (Vec::new(), Vec::new());
+        let page = colors.background.primary;
+
+        let b = &colors.button;
+        let schemes: [(&str, &ButtonColorScheme); 15] = [
+            ("primary_default", &b.primary_default), ("primary_disabled", &b.primary_disabled),
+            ("primary_hover", &b.primary_hover), ("primary_selected", &b.primary_selected),
+            ("primary_pressed", &b.primary_pressed),
+            ("secondary_default", &b.secondary_default), ("secondary_disabled", &b.secondary_disabled),
+            ("secondary_hover", &b.secondary_hover), ("secondary_selected", &b.secondary_selected),
+            ("secondary_pressed", &b.secondary_pressed),
+            ("ghost_default", &b.ghost_default), ("ghost_disabled", &b.ghost_disabled),
+            ("ghost_hover", &b.ghost_hover), ("ghost_selected", &b.ghost_selected),
+            ("ghost_pressed", &b.ghost_pressed),
+        ];
+        for (name, scheme) in schemes {
+            let background = over(scheme.background, page);
+            let pair = ContrastPair::new(format!("button.{}", name), over(scheme.label, background), background, TEXT);
+            if name.ends_with("_disabled") {disabled.push(pair)} else {pairs.push(pair)}
+        }
+
+        let styles = [
+            ("heading", TextStyle::Heading), ("primary", TextStyle::Primary), ("secondary", TextStyle::Secondary),
+            ("error", TextStyle::Error), ("keyboard", TextStyle::Keyboard),
+        ];
+        for (name, style) in styles {
+            for (surface, background) in [("primary", page), ("secondary", over(colors.background.secondary, page))] {
+                let foreground = over(style.color(colors), background);
+                pairs.push(ContrastPair::new(format!("text.{}.{}", name, surface), foreground, background, TEXT));
+            }
+        }
+        // White text sits on the brand color in the user's own message bubbles
+        let brand = over(colors.brand.primary, page);
+        pairs.push(ContrastPair::new("text.white.brand", over(TextStyle::White.color(colors), brand), brand, TEXT));
+
+        let avatars = [
+            ("primary", AvatarIconStyle::Primary), ("secondary", AvatarIconStyle::Secondary), ("brand", AvatarIconStyle::Brand),
+            ("success", AvatarIconStyle::Success), ("warning", AvatarIconStyle::Warning), ("danger", AvatarIconStyle::Danger),
+        ];
+        for (name, style) in avatars {
+            let (background, icon) = style.colors(colors);
+            let background = over(background, page);
+            pairs.push(ContrastPair::new(format!("avatar.{}", name), over(icon, background), background, ICON));
+        }
+
+        ContrastReport(pairs, disabled)
+    }
+
+    pub fn pairs(&self) -> &[ContrastPair] {&self.0}
+    pub fn disabled(&self) -> &[ContrastPair] {&self.1}
+
+    /// Pairings below AA.
+    pub fn failures(&self) -> Vec<&ContrastPair> {
+        self.0.iter().filter(|pair| !pair.aa).collect()
+    }
+
+    pub fn passes_aa(&self) -> bool {self.0.iter().all(|pair| pair.aa)}
+    pub fn passes_aaa(&self) -> bool {self.0.iter().all(|pair| pair.aaa)}
+
+    fn prefixed(mut self, prefix: &str) -> Self {
+        self.0.iter_mut().chain(self.1.iter_mut()).for_each(|pair| pair.name = format!("{}.{}", prefix, pair.name));
+        self
+    }
+}
+
+impl std::fmt::Display for ContrastReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.iter().try_for_each(|pair| writeln!(f, "{}", pair))?;
+        self.1.iter().try_for_each(|pair| writeln!(f, "{} (disabled)", pair))
+    }
+}
+
+impl Theme {
+    /// Audits both palettes, pair names start with `light.` or `dark.`.
+    ///
+    /// Building a `Theme` takes a `Context`, tests without one can audit a palette with `ContrastReport::audit`.
+    pub fn audit(&self) -> ContrastReport {
+        let mut report = ContrastReport::audit(&self.light).prefixed("light");
+        let dark = ContrastReport::audit(&self.dark).prefixed("dark");
+        report.0.extend(dark.0);
+        report.1.extend(dark.1);
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pair<'a>(report: &'a ContrastReport, name: &str) -> &'a ContrastPair {
+        report.pairs().iter().find(|pair| pair.name == name).unwrap_or_else(|| panic!("no pairing {}", name))
+    }
+
+    #[test]
+    fn dark_palette_pairings() {
+        let report = ContrastReport::audit(&ColorResources::dark());
+        let heading = pair(&report, "text.heading.primary");
+        assert!((heading.ratio - 21.0).abs() < 0.01 && heading.aaa);
+        assert!(pair(&report, "text.secondary.secondary").aa);
+        // White on the brand red is just under AA
+        for name in ["button.primary_default", "text.white.brand"] {
+            let brand = pair(&report, name);
+            assert!((brand.ratio - 4.13).abs() < 0.01 && !brand.aa, "{}", brand);
+        }
+        assert!(!report.passes_aa());
+    }
+
+    #[test]
+    fn light_palette_pairings() {
+        let report = ContrastReport::audit(&ColorResources::light());
+        assert!((pair(&report, "text.heading.primary").ratio - 21.0).abs() < 0.01);
+        let secondary = pair(&report, "text.secondary.primary");
+        assert!((secondary.ratio - 4.80).abs() < 0.01 && secondary.aa && !secondary.aaa);
+        assert!(!pair(&report, "text.secondary.secondary").aa);
+        // White stays white on the light palette, so the brand red is just under AA there too
+        let brand = pair(&report, "text.white.brand");
+        assert!((brand.ratio - 4.13).abs() < 0.01 && !brand.aa, "{}", brand);
+        assert!(report.pairs().iter().all(|pair| !pair.name.starts_with("text.white.primary")));
+    }
+
+    #[test]
+    fn failures_and_names() {
+        let report = ContrastReport::audit(&ColorResources::dark()).prefixed("dark");
+        assert!(report.failures().iter().all(|pair| !pair.aa && pair.name.starts_with("dark.")));
+        assert_eq!(pair(&report, "dark.text.white.brand").to_string(), "dark.text.white.brand: 4.13:1 (fail)");
+    }
+
+    #[test]
+    fn disabled_buttons_are_reported_apart() {
+        for colors in [ColorResources::dark(), ColorResources::light()] {
+            let report = ContrastReport::audit(&colors);
+            assert_eq!(report.disabled().len(), 3);
+            assert!(report.disabled().iter().all(|pair| pair.name.ends_with("_disabled")));
+            assert!(report.pairs().iter().chain(report.failures()).all(|pair| !pair.name.ends_with("_disabled")));
+        }
+        let report = ContrastReport::audit(&ColorResources::dark()).prefixed("dark");
+        assert!(report.disabled().iter().all(|pair| pair.name.starts_with("dark.button.")));
+    }
+}
@@ -44,10 +44,14 @@ impl Illustrations {
         Self(illustrations)
     }
 
-    pub fn get(&self, name: &'static str) -> resources::Image {
-        self.0.get(name).unwrap_or_else(|| panic!("Could not find illustration {:?}", name)).clone()
+    /// `None` for a name that was never added.
+    pub fn get(&self, name: &str) -> Option<resources::Image> {
+        self.0.get(name).cloned()
     }
 
+    /// The registered key for `name`, so reloading a name does not allocate a new one.
+    pub fn key(&self, name: &str) -> Option<&'static str> {self.0.get_key_value(name).map(|(key, _)| *key)}
+
     pub fn add_icon(&mut self, name: &'static str, illustration: resources::Image) {
         if let Some(existing) = self.0.get_mut(&name) {
             *existing = illustration; 
@@ -25,6 +25,35 @@ impl ColorResources {
     ) -> Self {
         ColorResources { background, outline, status, text, brand, shades, button }
     }
+
+    pub fn dark() -> Self {ColorResources::default()}
+
+    pub fn light() -> Self {
+        ColorResources {
+            background: BackgroundColor {
+                primary: Color::from_hex("ffffff", 255),
+                secondary: Color::from_hex("f5f5f4", 255),
+            },
+            outline: OutlineColor {
+                primary: Color::from_hex("000000", 255),
+                secondary: Color::from_hex("d6d3d1", 255),
+            },
+            status: StatusColor {
+                success: Color::from_hex("2fa84a", 255),
+                warning: Color::from_hex("d9a206", 255),
+                danger: Color::from_hex("e02d0a", 255),
+            },
+            text: TextColor {
+                heading: Color::from_hex("000000", 255),
+                primary: Color::from_hex("292524", 255),
+                secondary: Color::from_hex("78716c", 255),
+                danger: Color::from_hex("e02d0a", 255),
+            },
+            button: ButtonColors::light(),
+            brand: BrandColor::default(),
+            shades: ShadesColor::default(),
+        }
+    }
 }
 
 #[derive(Copy, Clone)]
@@ -240,9 +269,277 @@ impl Default for ButtonColors {
     }
 }
 
+impl ButtonColors {
+    pub fn light() -> Self {
+        let scheme = |background: &str, b: u8, label: &str, outline: &str, o: u8| ButtonColorScheme {
+            background: Color::from_hex(background, b),
+            label: Color::from_hex(label, 255),
+            outline: Color::from_hex(outline, o),
+        };
+        ButtonColors {
+            primary_default: scheme("eb343a", 255, "ffffff", "000000", 0),
+            primary_disabled: scheme("e7e5e4", 255, "a8a29e", "000000", 0),
+            primary_hover: scheme("da282e", 255, "ffffff", "000000", 0),
+            primary_selected: scheme("da282e", 255, "ffffff", "000000", 0),
+            primary_pressed: scheme("da282e", 255, "ffffff", "000000", 0),
+
+            secondary_default: scheme("000000", 0, "000000", "d6d3d1", 255),
+            secondary_disabled: scheme("e7e5e4", 255, "a8a29e", "d6d3d1", 255),
+            secondary_hover: scheme("f5f5f4", 255, "000000", "d6d3d1", 255),
+            secondary_selected: scheme("f5f5f4", 255, "000000", "d6d3d1", 255),
+            secondary_pressed: scheme("f5f5f4", 255, "000000", "d6d3d1", 255),
+
+            ghost_default: scheme("000000", 0, "000000", "000000", 0),
+            ghost_disabled: scheme("000000", 0, "a8a29e", "000000", 0),
+            ghost_hover: scheme("f5f5f4", 255, "000000", "000000", 0),
+            ghost_selected: scheme("f5f5f4", 255, "000000", "000000", 0),
+            ghost_pressed: scheme("f5f5f4", 255, "000000", "000000", 0),
+        }
+    }
+}
+
 #[derive(Copy, Clone)]
 pub struct ButtonColorScheme {
     pub background: Color,
     pub label: Color,
     pub outline: Color,
 }
+
+/// A color in OKLCH, a perceptual space where equal steps in lightness look equal across hues.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Oklch {
+    pub l: f32,
+    pub c: f32,
+    pub h: f32,
+}
+
+impl Oklch {
+    pub fn new(l: f32, c: f32, h: f32) -> Self {Oklch{l, c, h}}
+
+    pub fn from_color(color: Color) -> Self {
+        let [r, g, b] = [color.0, color.1, color.2].map(|c| to_linear(c as f32 / 255.0));
+        let l = (0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b).cbrt();
+        let m = (0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b).cbrt();
+        let s = (0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b).cbrt();
+        let lightness = 0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s;
+        let a = 1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s;
+        let b = 0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s;
+        Oklch{l: lightness, c: a.hypot(b), h: b.atan2(a).to_degrees().rem_euclid(360.0)}
+    }
+
+    /// Converts back to sRGB, lowering chroma until the color fits so the lightness is kept.
+    pub fn to_color(self, alpha: u8) -> Color {
+        let l = self.l.clamp(0.0, 1.0);
+        let (mut low, mut high) = (0.0, self.c.max(0.0));
+        if Oklch::rgb(l, high, self.h).is_none() {
+            for _ in 0..16 {
+                let mid = (low + high) / 2.0;
+                if Oklch::rgb(l, mid, self.h).is_some() {low = mid} else {high = mid}
+            }
+            high = low;
+        }
+        let [r, g, b] = Oklch::rgb(l, high, self.h).unwrap_or([l, l, l]).map(|c| (from_linear(c) * 255.0).round() as u8);
+        Color::from_hex(&format!("{:02x}{:02x}{:02x}", r, g, b), alpha)
+    }
+
+    pub fn lighten(self, amount: f32) -> Self {Oklch{l: (self.l + amount).clamp(0.0, 1.0), ..self}}
+
+    // Linear sRGB channels, None when outside the gamut
+    fn rgb(l: f32, c: f32, h: f32) -> Option<[f32; 3]> {
+        let (a, b) = (c * h.to_radians().cos(), c * h.to_radians().sin());
+        let l_ = (l + 0.3963377774 * a + 0.2158037573 * b).powi(3);
+        let m_ = (l - 0.1055613458 * a - 0.0638541728 * b).powi(3);
+        let s_ = (l - 0.0894841775 * a - 1.2914855480 * b).powi(3);
+        let rgb = [
+            4.0767416621 * l_ - 3.3077115913 * m_ + 0.2309699292 * s_,
+            -1.2684380046 * l_ + 2.6097574011 * m_ - 0.3413193965 * s_,
+            -0.0041960863 * l_ - 0.7034186147 * m_ + 1.7076147010 * s_,
+        ];
+        rgb.iter().all(|c| (-0.0001..=1.0001).contains(c)).then(|| rgb.map(|c| c.clamp(0.0, 1.0)))
+    }
+}
+
+fn to_linear(c: f32) -> f32 {
+    if c <= 0.04045 {c / 12.92} else {((c + 0.055) / 1.055).powf(2.4)}
+}
+
+fn from_linear(c: f32) -> f32 {
+    if c <= 0.0031308 {c * 12.92} else {1.055 * c.powf(1.0 / 2.4) - 0.055}
+}
+
+/// WCAG relative luminance of the color, ignoring alpha.
+pub fn luminance(color: Color) -> f32 {
+    let [r, g, b] = [color.0, color.1, color.2].map(|c| to_linear(c as f32 / 255.0));
+    0.2126 * r + 0.7152 * g + 0.0722 * b
+}
+
+/// WCAG contrast ratio between two opaque colors, from 1 to 21.
+pub fn contrast(a: Color, b: Color) -> f32 {
+    let (a, b) = (luminance(a), luminance(b));
+    (a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
+
+/// Composites a translucent color onto an opaque one.
+pub fn over(top: Color, bottom: Color) -> Color {
+    let alpha = top.3 as f32 / 255.0;
+    let mix = |t: u8, b: u8| (t as f32 * alpha + b as f32 * (1.0 - alpha)).round() as u8;
+    Color::from_hex(&format!("{:02x}{:02x}{:02x}", mix(top.0, bottom.0), mix(top.1, bottom.1), mix(top.2, bottom.2)), 255)
+}
+
+impl ColorResources {
+    /// Outlines at text strength, secondary text promoted to primary, the lighten shades
+    /// behind keys and labels flattened onto the page background and the scrims darkened.
+    pub fn high_contrast(mut self) -> Self {
+        let page = self.background.primary;
+        self.outline.primary = self.text.heading;
+        self.outline.secondary = self.text.primary;
+        self.text.secondary = self.text.primary;
+        let shades = &mut self.shades;
+        for shade in [&mut shades.lighten, &mut shades.lighten2] {
+            *shade = over(*shade, page);
+        }
+        // Scrims stay translucent so the page behind a modal still shows, halfway to opaque
+        for shade in [&mut shades.darken, &mut shades.darken2] {
+            shade.3 = ((shade.3 as u16 + 255) / 2) as u8;
+        }
+        // Visible button outlines use the strengthened outline colors, hidden ones stay hidden
+        let b = &mut self.button;
+        for scheme in [
+            &mut b.primary_default, &mut b.primary_disabled, &mut b.primary_hover, &mut b.primary_selected, &mut b.primary_pressed,
+            &mut b.secondary_default, &mut b.secondary_disabled, &mut b.secondary_hover, &mut b.secondary_selected, &mut b.secondary_pressed,
+            &mut b.ghost_default, &mut b.ghost_disabled, &mut b.ghost_hover, &mut b.ghost_selected, &mut b.ghost_pressed,
+        ] {
+            if scheme.outline.3 > 0 {scheme.outline = self.outline.secondary;}
+        }
+        self
+    }
+}
+
+impl ColorResources {
+    /// Derives a complete palette from a brand color. Grays take the hue of `neutral`,
+    /// or a faint tint of the brand, and every shade is picked by OKLCH lightness.
+    pub fn generate(brand: Color, neutral: Option<Color>, dark: bool) -> Self {
+        let accent = Oklch::from_color(brand);
+        let tint = neutral.map(Oklch::from_color).map(|n| (n.c.min(0.05), n.h)).unwrap_or((accent.c.min(0.2) * 0.08, accent.h));
+        let tinted = |l: f32, alpha: u8| Oklch::new(l, tint.0, tint.1).to_color(alpha);
+        let gray = |l: f32| tinted(l, 255);
+        // Lightness steps for (background, raised, outline, disabled, disabled label, secondary text, primary text, heading)
+        let [bg, raised, line, off, muted, secondary, primary, heading] = match dark {
+            true => [0.0, 0.25, 0.45, 0.32, 0.72, 0.74, 0.91, 1.0],
+            false => [1.0, 0.97, 0.87, 0.92, 0.5, 0.55, 0.27, 0.0],
+        };
+        let transparent = Color::from_hex("000000", 0);
+
+        // Labels on the brand color are black or white, whichever reads better
+        let on_brand = match contrast(brand, gray(1.0)) >= contrast(brand, gray(0.0)) {
+            true => gray(1.0),
+            false => gray(0.0),
+        };
+        let shift = if dark {-0.05} else {-0.07};
+        let brand_active = accent.lighten(shift).to_color(255);
+        let status = |h: f32| Oklch::new(if dark {0.7} else {0.58}, 0.17, h).to_color(255);
+
+        let scheme = |background: Color, label: Color, outline: Color| ButtonColorScheme{background, label, outline};
+        let disabled = scheme(gray(off), gray(muted), transparent);
+        let hovered = scheme(gray(raised), gray(heading), gray(line));
+        let ghost = scheme(gray(raised), gray(heading), transparent);
+
+        ColorResources {
+            background: BackgroundColor{primary: gray(bg), secondary: gray(raised)},
+            outline: OutlineColor{primary: gray(heading), secondary: gray(line)},
+            status: StatusColor{success: status(145.0), warning: status(85.0), danger: status(29.0)},
+            text: TextColor{heading: gray(heading), primary: gray(primary), secondary: gray(secondary), danger: status(29.0)},
+            brand: BrandColor{primary: brand, secondary: on_brand},
+            // Same alphas as the default shades, on the tinted ends of the gray ramp
+            shades: ShadesColor{
+                black: gray(0.0), white: gray(1.0),
+                lighten: tinted(1.0, 110), lighten2: tinted(1.0, 180),
+                darken: tinted(0.0, 110), darken2: tinted(0.0, 180),
+                transparent,
+            },
+            button: ButtonColors {
+                primary_default: scheme(brand, on_brand, transparent),
+                primary_disabled: disabled,
+                primary_hover: scheme(brand_active, on_brand, transparent),
+                primary_selected: scheme(brand_active, on_brand, transparent),
+                primary_pressed: scheme(accent.lighten(shift * 1.5).to_color(255), on_brand, transparent),
+
+                secondary_default: scheme(transparent, gray(heading), gray(line)),
+                secondary_disabled: scheme(gray(off), gray(muted), gray(line)),
+                secondary_hover: hovered,
+                secondary_selected: hovered,
+                secondary_pressed: hovered,
+
+                ghost_default: scheme(transparent, gray(heading), transparent),
+                ghost_disabled: scheme(transparent, gray(muted), transparent),
+                ghost_hover: ghost,
+                ghost_selected: ghost,
+                ghost_pressed: ghost,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rgb(color: Color) -> (u8, u8, u8) {(color.0, color.1, color.2)}
+
+    #[test]
+    fn oklch_round_trips_srgb() {
+        for hex in ["000000", "ffffff", "ff0000", "00ff00", "0000ff", "eb343a", "5b8def", "808080", "1a2b3c"] {
+            let color = Color::from_hex(hex, 255);
+            let back = Oklch::from_color(color).to_color(255);
+            let close = |a: u8, b: u8| a.abs_diff(b) <= 1;
+            assert!(close(color.0, back.0) && close(color.1, back.1) && close(color.2, back.2), "{} became {:?}", hex, rgb(back));
+        }
+    }
+
+    #[test]
+    fn oklch_keeps_lightness_out_of_gamut() {
+        let color = Oklch::new(0.7, 0.5, 145.0).to_color(255);
+        assert!((Oklch::from_color(color).l - 0.7).abs() < 0.01);
+    }
+
+    #[test]
+    fn luminance_and_contrast_match_wcag() {
+        let (black, white) = (Color::from_hex("000000", 255), Color::from_hex("ffffff", 255));
+        assert_eq!(luminance(black), 0.0);
+        assert!((luminance(white) - 1.0).abs() < 1e-4);
+        assert!((contrast(black, white) - 21.0).abs() < 0.01);
+        assert_eq!(contrast(white, black), contrast(black, white));
+        assert!((contrast(Color::from_hex("777777", 255), white) - 4.48).abs() < 0.01);
+    }
+
+    #[test]
+    fn over_composites_onto_the_bottom_color() {
+        let white = Color::from_hex("ffffff", 255);
+        assert_eq!(rgb(over(Color::from_hex("000000", 0), white)), (255, 255, 255));
+        assert_eq!(rgb(over(Color::from_hex("000000", 255), white)), (0, 0, 0));
+        assert_eq!(rgb(over(Color::from_hex("000000", 128), white)), (127, 127, 127));
+    }
+
+    #[test]
+    fn generated_shades_come_from_the_gray_ramp() {
+        let colors = ColorResources::generate(Color::from_hex("5b8def", 255), None, true);
+        let shades = colors.shades;
+        assert_eq!(rgb(shades.black), rgb(colors.background.primary));
+        assert_eq!(rgb(shades.white), rgb(colors.text.heading));
+        assert_eq!(rgb(shades.lighten), rgb(shades.white));
+        assert_eq!(rgb(shades.darken2), rgb(shades.black));
+        assert_eq!([shades.lighten.3, shades.lighten2.3, shades.darken.3, shades.darken2.3, shades.transparent.3], [110, 180, 110, 180, 0]);
+    }
+
+    #[test]
+    fn high_contrast_strengthens_visible_outlines() {
+        let colors = ColorResources::dark().high_contrast();
+        assert_eq!(rgb(colors.outline.secondary), rgb(colors.text.primary));
+        assert_eq!(rgb(colors.button.secondary_default.outline), rgb(colors.text.primary));
+        assert_eq!(rgb(colors.button.secondary_hover.outline), rgb(colors.text.primary));
+        assert_eq!(colors.button.ghost_default.outline.3, 0);
+        assert_eq!(colors.button.primary_default.outline.3, 0);
+        assert_eq!(colors.shades.lighten.3, 255);
+        assert_eq!((colors.shades.darken.3, colors.shades.darken2.3), (182, 217));
+    }
+}
@@ -76,6 +76,11 @@ impl IconResources {
         self.0.get(name).unwrap_or_else(|| panic!("Could not find icon {:?}", name)).clone()
     }
 
+    pub fn has_icon(&self, name: &str) -> bool {self.0.contains_key(name)}
+
+    /// The registered key for `name`, so reloading a name does not allocate a new one.
+    pub fn key(&self, name: &str) -> Option<&'static str> {self.0.get_key_value(name).map(|(key, _)| *key)}
+
     pub fn add_icon(&mut self, icon_name: &'static str, icon: resources::Image) {
         if let std::collections::hash_map::Entry::Vacant(e) = self.0.entry(icon_name) {
             e.insert(icon);
@@ -0,0 +1,320 @@
+use rust_on_rails::prelude::*;
+use serde_json::{Map, Value};
+
+use super::Theme;
+use super::colors::{ColorResources, ButtonColorScheme};
+
+use std::fmt::Display;
+use std::path::Path;
+
+const QUALITY: f32 = 8.0; // svg rasterization quality, matches the bundled resources
+
+/// Problem found while building a `Theme` from a token file. Paths are dotted, e.g. `colors.dark.text.primary`.
+#[derive(Debug)]
+pub enum ThemeError {
+    Io(std::io::Error),
+    Json(serde_json::Error),
+    /// A token with a value of the wrong type or format.
+    Malformed{path: String, expected: &'static str},
+    /// A token naming a font or image that is not in the assets.
+    MissingAsset{path: String, file: String},
+    /// A key the theme has no slot for, usually a typo.
+    UnknownToken(String),
+}
+
+impl Display for ThemeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ThemeError::Io(e) => write!(f, "Could not read the token file: {}", e),
+            ThemeError::Json(e) => write!(f, "Token file is not valid JSON: {}", e),
+            ThemeError::Malformed{path, expected} => write!(f, "Token {} should be {}", path, expected),
+            ThemeError::MissingAsset{path, file} => write!(f, "Token {} names {} which is not an asset", path, file),
+            ThemeError::UnknownToken(path) => write!(f, "Unknown token {}", path),
+        }
+    }
+}
+
+impl std::error::Error for ThemeError {}
+
+impl From<std::io::Error> for ThemeError {
+    fn from(e: std::io::Error) -> Self {ThemeError::Io(e)}
+}
+
+impl From<serde_json::Error> for ThemeError {
+    fn from(e: serde_json::Error) -> Self {ThemeError::Json(e)}
+}
+
+impl Theme {
+    pub fn load_tokens(ctx: &mut Context, path: impl AsRef<Path>) -> Result<Self, ThemeError> {
+        let json = std::fs::read_to_string(path)?;
+        Theme::from_tokens(ctx, &json)
+    }
+
+    /// Builds a theme from design tokens, every omitted token keeps its value from `Theme::default`.
+    ///
+    /// The top level holds `colors` (`light` and `dark` palettes of `#rrggbb` or `#rrggbbaa` values),
+    /// `fonts` (asset paths), `sizes` (points), `icons` (name to svg path) and `brand` (svg paths).
+    pub fn from_tokens(ctx: &mut Context, json: &str) -> Result<Self, ThemeError> {
+        let value: Value = serde_json::from_str(json)?;
+        let root = Tokens::new(&value, String::new())?;
+        root.known(&["colors", "fonts", "sizes", "icons", "brand"])?;
+        let mut theme = Theme::default(ctx);
+
+        if let Some(colors) = root.child("colors")? {
+            colors.known(&["light", "dark"])?;
+            if let Some(light) = colors.child("light")? {palette(&light, &mut theme.light)?;}
+            if let Some(dark) = colors.child("dark")? {palette(&dark, &mut theme.dark)?;}
+        }
+
+        if let Some(fonts) = root.child("fonts")? {
+            fonts.known(&["heading", "text", "label", "keyboard", "emoji"])?;
+            let slots = &mut theme.fonts.fonts;
+            for (key, slot) in [
+                ("heading", &mut slots.heading), ("text", &mut slots.text), ("label", &mut slots.label),
+                ("keyboard", &mut slots.keyboard), ("emoji", &mut slots.emoji),
+            ] {
+                if let Some((file, path)) = fonts.string(key)? {
+                    *slot = ctx.load_font(file).ok_or_else(|| ThemeError::MissingAsset{path, file: file.to_string()})?;
+                }
+            }
+        }
+
+        if let Some(sizes) = root.child("sizes")? {
+            let size = &mut theme.fonts.size;
+            let slots = [
+                ("title", &mut size.title), ("h1", &mut size.h1), ("h2", &mut size.h2), ("h3", &mut size.h3),
+                ("h4", &mut size.h4), ("h5", &mut size.h5), ("h6", &mut size.h6), ("xl", &mut size.xl),
+                ("lg", &mut size.lg), ("md", &mut size.md), ("sm", &mut size.sm), ("xs", &mut size.xs),
+            ];
+            sizes.known(&slots.iter().map(|(key, _)| *key).collect::<Vec<_>>())?;
+            for (key, slot) in slots {
+                if let Some(number) = sizes.number(key)? {*slot = number;}
+            }
+        }
+
+        if let Some(icons) = root.child("icons")? {
+            for key in icons.0.keys() {
+                let (file, path) = icons.string(key)?.expect("key comes from the map");
+                let icon = svg(ctx, file, path)?;
+                match theme.icons.key(key) {
+                    Some(name) => theme.icons.set_icon(name, icon),
+                    None => theme.icons.add_icon(leak(key), icon),
+                }
+            }
+        }
+
+        if let Some(brand) = root.child("brand")? {
+            brand.known(&["wordmark", "logomark", "app_icon", "illustrations"])?;
+            for (key, slot) in [
+                ("wordmark", &mut theme.brand.wordmark), ("logomark", &mut theme.brand.logomark), ("app_icon", &mut theme.brand.app_icon),
+            ] {
+                if let Some((file, path)) = brand.string(key)? {*slot = svg(ctx, file, path)?;}
+            }
+            if let Some(illustrations) = brand.child("illustrations")? {
+                for key in illustrations.0.keys() {
+                    let (file, path) = illustrations.string(key)?.expect("key comes from the map");
+                    let illustration = svg(ctx, file, path)?;
+                    let name = theme.brand.illustrations.key(key).unwrap_or_else(|| leak(key));
+                    theme.brand.illustrations.add_icon(name, illustration);
+                }
+            }
+        }
+
+        Ok(theme)
+    }
+}
+
+fn palette(tokens: &Tokens, colors: &mut ColorResources) -> Result<(), ThemeError> {
+    tokens.known(&["background", "outline", "status", "text", "brand", "shades", "button"])?;
+    tokens.colors("background", vec![("primary", &mut colors.background.primary), ("secondary", &mut colors.background.secondary)])?;
+    tokens.colors("outline", vec![("primary", &mut colors.outline.primary), ("secondary", &mut colors.outline.secondary)])?;
+    tokens.colors("brand", vec![("primary", &mut colors.brand.primary), ("secondary", &mut colors.brand.secondary)])?;
+    tokens.colors("status", vec![
+        ("success", &mut colors.status.success), ("warning", &mut colors.status.warning), ("danger", &mut colors.status.danger),
+    ])?;
+    tokens.colors("text", vec![
+        ("heading", &mut colors.text.heading), ("primary", &mut colors.text.primary),
+        ("secondary", &mut colors.text.secondary), ("danger", &mut colors.text.danger),
+    ])?;
+    let shades = &mut colors.shades;
+    tokens.colors("shades", vec![
+        ("black", &mut shades.black), ("white", &mut shades.white), ("lighten", &mut shades.lighten),
+        ("lighten2", &mut shades.lighten2), ("darken", &mut shades.darken), ("darken2", &mut shades.darken2),
+        ("transparent", &mut shades.transparent),
+    ])?;
+
+    if let Some(button) = tokens.child("button")? {
+        let b = &mut colors.button;
+        let schemes: Vec<(&str, &mut ButtonColorScheme)> = vec![
+            ("primary_default", &mut b.primary_default), ("primary_disabled", &mut b.primary_disabled),
+            ("primary_hover", &mut b.primary_hover), ("primary_selected", &mut b.primary_selected),
+            ("primary_pressed", &mut b.primary_pressed),
+            ("secondary_default", &mut b.secondary_default), ("secondary_disabled", &mut b.secondary_disabled),
+            ("secondary_hover", &mut b.secondary_hover), ("secondary_selected", &mut b.secondary_selected),
+            ("secondary_pressed", &mut b.secondary_pressed),
+            ("ghost_default", &mut b.ghost_default), ("ghost_disabled", &mut b.ghost_disabled),
+            ("ghost_hover", &mut b.ghost_hover), ("ghost_selected", &mut b.ghost_selected),
+            ("ghost_pressed", &mut b.ghost_pressed),
+        ];
+        button.known(&schemes.iter().map(|(key, _)| *key).collect::<Vec<_>>())?;
+        for (key, scheme) in schemes {
+            button.colors(key, vec![
+                ("background", &mut scheme.background), ("label", &mut scheme.label), ("outline", &mut scheme.outline),
+            ])?;
+        }
+    }
+    Ok(())
+}
+
+// Names are keyed by `&'static str`, only names that are not registered yet are leaked
+fn leak(name: &str) -> &'static str {Box::leak(name.to_string().into_boxed_str())}
+
+fn svg(ctx: &mut Context, file: &str, path: String) -> Result<resources::Image, ThemeError> {
+    let bytes = ctx.load_file(file).ok_or_else(|| ThemeError::MissingAsset{path, file: file.to_string()})?;
+    Ok(ctx.add_svg(&bytes, QUALITY))
+}
+
+/// Parses `#rrggbb` or `#rrggbbaa`, the leading `#` is optional.
+pub fn parse_hex(value: &str) -> Option<Color> {
+    let hex = value.strip_prefix('#').unwrap_or(value);
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {return None;}
+    match hex.len() {
+        6 => Some(Color::from_hex(hex, 255)),
+        8 => Some(Color::from_hex(&hex[..6], u8::from_str_radix(&hex[6..], 16).ok()?)),
+        _ => None
+    }
+}
+
+// A JSON object in the token file along with its dotted path
+struct Tokens<'a>(&'a Map<String, Value>, String);
+
+impl<'a> Tokens<'a> {
+    fn new(value: &'a Value, path: String) -> Result<Self, ThemeError> {
+        match value {
+            Value::Object(map) => Ok(Tokens(map, path)),
+            _ => Err(ThemeError::Malformed{path, expected: "an object"})
+        }
+    }
+
+    fn path(&self, key: &str) -> String {
+        if self.1.is_empty() {key.to_string()} else {format!("{}.{}", self.1, key)}
+    }
+
+    fn known(&self, keys: &[&str]) -> Result<(), ThemeError> {
+        match self.0.keys().find(|key| !keys.contains(&key.as_str())) {
+            Some(key) => Err(ThemeError::UnknownToken(self.path(key))),
+            None => Ok(())
+        }
+    }
+
+    fn child(&self, key: &str) -> Result<Option<Tokens<'a>>, ThemeError> {
+        self.0.get(key).map(|value| Tokens::new(value, self.path(key))).transpose()
+    }
+
+    fn string(&self, key: &str) -> Result<Option<(&'a str, String)>, ThemeError> {
+        match self.0.get(key) {
+            None => Ok(None),
+            Some(Value::String(s)) => Ok(Some((s.as_str(), self.path(key)))),
+            Some(_) => Err(ThemeError::Malformed{path: self.path(key), expected: "a string"})
+        }
+    }
+
+    fn number(&self, key: &str) -> Result<Option<f32>, ThemeError> {
+        match self.0.get(key) {
+            None => Ok(None),
+            Some(Value::Number(n)) if n.as_f64().is_some_and(|n| n > 0.0) => Ok(n.as_f64().map(|n| n as f32)),
+            Some(_) => Err(ThemeError::Malformed{path: self.path(key), expected: "a positive number"})
+        }
+    }
+
+    // Fills the listed colors of the `key` group that are present
+    fn colors(&self, key: &str, slots: Vec<(&str, &mut Color)>) -> Result<(), ThemeError> {
+        let Some(group) = self.child(key)? else {return Ok(())};
+        group.known(&slots.iter().map(|(key, _)| *key).collect::<Vec<_>>())?;
+        for (key, slot) in slots {
+            if let Some((value, path)) = group.string(key)? {
+                *slot = parse_hex(value).ok_or(ThemeError::Malformed{path, expected: "a #rrggbb or #rrggbbaa color"})?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn rgba(color: Color) -> (u8, u8, u8, u8) {(color.0, color.1, color.2, color.3)}
+
+    #[test]
+    fn parse_hex_reads_rgb_and_rgba() {
+        assert_eq!(parse_hex("#1a2b3c").map(rgba), Some((0x1a, 0x2b, 0x3c, 255)));
+        assert_eq!(parse_hex("1A2B3C").map(rgba), Some((0x1a, 0x2b, 0x3c, 255)));
+        assert_eq!(parse_hex("#1a2b3c80").map(rgba), Some((0x1a, 0x2b, 0x3c, 0x80)));
+    }
+
+    #[test]
+    fn parse_hex_rejects_bad_values() {
+        for value in ["", "#", "#fff", "#1a2b3", "#1a2b3c8", "#1a2b3c8000", "#1a2b3g", "##1a2b3c", "#+1a2b3c"] {
+            assert!(parse_hex(value).is_none(), "{:?}", value);
+        }
+    }
+
+    #[test]
+    fn tokens_must_be_objects() {
+        let value = json!({"colors": "dark"});
+        let root = Tokens::new(&value, String::new()).unwrap();
+        match root.child("colors") {
+            Err(ThemeError::Malformed{path, expected}) => assert_eq!((path.as_str(), expected), ("colors", "an object")),
+            other => panic!("{:?}", other.map(|_| ())),
+        }
+        assert!(matches!(Tokens::new(&json!([]), String::new()), Err(ThemeError::Malformed{..})));
+    }
+
+    #[test]
+    fn unknown_tokens_report_their_path() {
+        let value = json!({"text": {"primry": "#ffffff"}});
+        let root = Tokens::new(&value, "colors.dark".to_string()).unwrap();
+        let mut color = Color::from_hex("000000", 255);
+        match root.colors("text", vec![("primary", &mut color)]) {
+            Err(ThemeError::UnknownToken(path)) => assert_eq!(path, "colors.dark.text.primry"),
+            other => panic!("{:?}", other),
+        }
+    }
+
+    #[test]
+    fn colors_fill_present_slots() {
+        let value = json!({"text": {"primary": "#ff000080"}});
+        let root = Tokens::new(&value, String::new()).unwrap();
+        let (mut primary, mut secondary) = (Color::from_hex("000000", 255), Color::from_hex("000000", 255));
+        root.colors("text", vec![("primary", &mut primary), ("secondary", &mut secondary)]).unwrap();
+        assert_eq!(rgba(primary), (255, 0, 0, 0x80));
+        assert_eq!(rgba(secondary), (0, 0, 0, 255));
+    }
+
+    #[test]
+    fn malformed_values_report_what_was_expected() {
+        let value = json!({"text": {"primary": "red"}, "sizes": {"md": -4, "lg": "big"}, "name": 3});
+        let root = Tokens::new(&value, String::new()).unwrap();
+        let mut color = Color::from_hex("000000", 255);
+        assert!(matches!(
+            root.colors("text", vec![("primary", &mut color)]),
+            Err(ThemeError::Malformed{path, expected: "a #rrggbb or #rrggbbaa color"}) if path == "text.primary"
+        ));
+        let sizes = root.child("sizes").unwrap().unwrap();
+        assert!(matches!(sizes.number("md"), Err(ThemeError::Malformed{path, ..}) if path == "sizes.md"));
+        assert!(matches!(sizes.number("lg"), Err(ThemeError::Malformed{path, ..}) if path == "sizes.lg"));
+        assert!(matches!(sizes.number("sm"), Ok(None)));
+        assert!(matches!(root.string("name"), Err(ThemeError::Malformed{expected: "a string", ..})));
+    }
+
+    #[test]
+    fn errors_name_the_token() {
+        let error = ThemeError::MissingAsset{path: "fonts.text".to_string(), file: "fonts/missing.ttf".to_string()};
+        assert_eq!(error.to_string(), "Token fonts.text names fonts/missing.ttf which is not an asset");
+        assert_eq!(ThemeError::UnknownToken("sizes.huge".to_string()).to_string(), "Unknown token sizes.huge");
+        let json = serde_json::from_str::<Value>("{").unwrap_err();
+        assert!(matches!(ThemeError::from(json), ThemeError::Json(_)));
+    }
+}